@@ -0,0 +1,94 @@
+//! Test de recette de l'allocateur : plusieurs tâches alternent allocations
+//! et libérations sur des catégories distinctes, puis le programme vérifie
+//! qu'aucune allocation n'a échoué et qu'aucune opération n'a dépassé le
+//! délai.
+//!
+//! ```text
+//! cargo run --release --example stress --features bench -- [charge %] [tours] [délai ns]
+//! ```
+//!
+//! Sur un hôte, les préemptions du système d'exploitation apparaissent comme
+//! des pointes de quelques millisecondes, que le délai par défaut (10 ms)
+//! tolère. Sur la cible, chaque tâche de l'exécutif temps réel appelle
+//! [`bench::stress`] de la même façon, avec le compteur de cycles comme
+//! horloge et le délai visé.
+
+use std::process::ExitCode;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Instant;
+
+use global_allocator::bench::{self, StressConfig, StressReport};
+use global_allocator::{ArenaConfig, BlockSize, SlabMemory};
+
+/// Taille du tas de démonstration.
+const HEAP_SIZE: usize = 256 * 1024;
+
+/// Catégories sollicitées par chaque tâche.
+const WORKLOADS: [&[BlockSize]; 2] = [
+    &[BlockSize::Tiny, BlockSize::Medium, BlockSize::Huge, BlockSize::Colossal],
+    &[BlockSize::Small, BlockSize::Large, BlockSize::Giant, BlockSize::Mammoth],
+];
+
+/// Instant de référence de l'horloge.
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// Horloge du test, en nanosecondes.
+fn nanos() -> u32 {
+    EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as u32
+}
+
+/// Lit l'argument `position` de la ligne de commande, ou `default`.
+fn argument(position: usize, default: usize) -> usize {
+    std::env::args().nth(position).and_then(|arg| arg.parse().ok()).unwrap_or(default)
+}
+
+fn main() -> ExitCode {
+    let load_percent = argument(1, 75);
+    let rounds = argument(2, 100_000);
+    let deadline = argument(3, 10_000_000) as u32;
+
+    let heap: &'static mut [u8] = Vec::leak(vec![0; HEAP_SIZE]);
+    unsafe { SlabMemory::initialize_with(heap.as_mut_ptr(), heap.len(), ArenaConfig::preset_medium()) };
+    nanos();
+
+    let reports: Vec<StressReport> = thread::scope(|scope| {
+        let tasks: Vec<_> = WORKLOADS
+            .iter()
+            .map(|classes| {
+                scope.spawn(move || {
+                    bench::stress(&StressConfig {
+                        classes,
+                        load_percent,
+                        rounds,
+                        clock: nanos,
+                    })
+                })
+            })
+            .collect();
+        tasks.into_iter().map(|task| task.join().unwrap()).collect()
+    });
+
+    let mut passed = true;
+    for (classes, report) in WORKLOADS.iter().zip(&reports) {
+        for block_size in classes.iter() {
+            let index = block_size.index();
+            println!(
+                "{:>5} B: max alloc {:>8} ns, max free {:>8} ns",
+                block_size.size(),
+                report.max_allocate[index],
+                report.max_deallocate[index]
+            );
+        }
+        println!("  {} allocations, {} failures", report.allocations, report.failures);
+        passed &= report.meets(deadline);
+    }
+
+    if passed {
+        println!("PASS: no failure at {}% load, every operation under {} ns", load_percent, deadline);
+        ExitCode::SUCCESS
+    } else {
+        println!("FAIL: failures or operations over {} ns", deadline);
+        ExitCode::FAILURE
+    }
+}
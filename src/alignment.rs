@@ -0,0 +1,58 @@
+use core::alloc::Layout;
+#[cfg(all(feature = "alignment-audit", feature = "debug"))]
+use core::fmt::Write;
+#[cfg(feature = "alignment-audit")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use crate::config::BlockSize;
+#[cfg(all(feature = "alignment-audit", feature = "debug"))]
+use crate::debug::DebugWriter;
+
+/// Plus grand alignement demandé dans chaque catégorie, indexé comme les arènes.
+#[cfg(feature = "alignment-audit")]
+static MAX_ALIGN: [AtomicUsize; 8] = [const { AtomicUsize::new(0) }; 8];
+
+/// Nombre de demandes refusées parce que leur alignement dépassait celui des
+/// blocs, par catégorie.
+#[cfg(feature = "alignment-audit")]
+static OVER_ALIGNED: [AtomicUsize; 8] = [const { AtomicUsize::new(0) }; 8];
+
+/// Enregistre l'alignement d'une demande.
+///
+/// Une demande dont l'alignement dépasse `guaranteed`, l'alignement garanti
+/// des pointeurs de sa catégorie, est refusée par l'allocateur : elle est
+/// comptée et signalée sur la sortie de débogage.
+#[cfg(feature = "alignment-audit")]
+pub(crate) fn audit(block_size: BlockSize, layout: Layout, guaranteed: usize) {
+    let index = block_size.index();
+    MAX_ALIGN[index].fetch_max(layout.align(), Ordering::Relaxed);
+    if layout.align() <= guaranteed {
+        return;
+    }
+    OVER_ALIGNED[index].fetch_add(1, Ordering::Relaxed);
+    #[cfg(feature = "debug")]
+    let _ = writeln!(
+        DebugWriter,
+        "Allocation of {} bytes aligned to {} refused: {}-byte blocks are aligned to {}",
+        layout.size(),
+        layout.align(),
+        block_size.size(),
+        guaranteed
+    );
+}
+
+// Sans la feature `alignment-audit`, les demandes trop alignées sont refusées
+// sans être comptées ni signalées.
+#[cfg(not(feature = "alignment-audit"))]
+pub(crate) fn audit(_block_size: BlockSize, _layout: Layout, _guaranteed: usize) {}
+
+/// Retourne le plus grand alignement demandé dans la catégorie d'index `index`.
+#[cfg(feature = "alignment-audit")]
+pub(crate) fn max_alignment(index: usize) -> usize {
+    MAX_ALIGN[index].load(Ordering::Relaxed)
+}
+
+/// Retourne le nombre de demandes trop alignées de la catégorie d'index `index`.
+#[cfg(feature = "alignment-audit")]
+pub(crate) fn over_aligned(index: usize) -> usize {
+    OVER_ALIGNED[index].load(Ordering::Relaxed)
+}
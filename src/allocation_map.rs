@@ -0,0 +1,359 @@
+use core::alloc::Layout;
+#[cfg(all(feature = "allocation-map", feature = "debug"))]
+use core::fmt::Write;
+#[cfg(feature = "allocation-map")]
+use core::ptr;
+#[cfg(feature = "allocation-map")]
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+#[cfg(any(feature = "allocation-map", feature = "reservations"))]
+use crate::config::BlockSize;
+#[cfg(all(feature = "allocation-map", feature = "debug"))]
+use crate::debug::DebugWriter;
+#[cfg(feature = "allocation-map")]
+use crate::header::HEADER_SIZE;
+
+/// Une entrée du plan d'allocation : au plus `count` allocations simultanées
+/// de `size` octets.
+///
+/// Les entrées sont déclarées à la compilation avec [`allocation_map!`].
+///
+/// Disponible uniquement avec la feature `allocation-map`.
+#[cfg(feature = "allocation-map")]
+pub struct AllocationEntry {
+    /// Taille exacte des allocations autorisées, en octets.
+    size: usize,
+    /// Nombre maximal d'allocations simultanées de cette taille.
+    count: usize,
+    /// Nombre d'allocations de cette taille actuellement vivantes.
+    live: AtomicUsize,
+}
+
+#[cfg(feature = "allocation-map")]
+impl AllocationEntry {
+    /// Crée une entrée autorisant `count` allocations simultanées de `size` octets.
+    ///
+    /// Évaluée à la compilation, une taille nulle ou trop grande pour les
+    /// catégories de l'allocateur est une erreur de compilation.
+    pub const fn new(size: usize, count: usize) -> Self {
+        assert!(
+            size != 0 && size <= BlockSize::MAX - HEADER_SIZE,
+            "allocation size outside the allocator size classes"
+        );
+        Self {
+            size,
+            count,
+            live: AtomicUsize::new(0),
+        }
+    }
+
+    /// Retourne la taille des allocations autorisées, en octets.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Retourne le nombre maximal d'allocations simultanées.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Retourne le nombre d'allocations actuellement vivantes.
+    pub fn live(&self) -> usize {
+        self.live.load(Ordering::Relaxed)
+    }
+}
+
+/// Erreur retournée par
+/// [`SlabMemory::set_allocation_map`](crate::SlabMemory::set_allocation_map).
+///
+/// Disponible uniquement avec la feature `allocation-map`.
+#[cfg(feature = "allocation-map")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AllocationMapError {
+    /// Un plan est déjà installé ; il ne peut pas être remplacé.
+    AlreadySet,
+    /// Des blocs ont déjà été alloués ou réservés : le plan ne pourrait pas
+    /// les compter.
+    AfterAllocation,
+    /// L'arène de cette catégorie ne peut pas contenir toutes les allocations
+    /// déclarées pour elle.
+    ExceedsArena(BlockSize),
+}
+
+#[cfg(feature = "allocation-map")]
+impl AllocationMapError {
+    /// Retourne le code stable et négatif de l'erreur, pour une interface FFI
+    /// (voir la table des codes du readme).
+    ///
+    /// [`AllocationMapError::AlreadySet`] vaut `-48`,
+    /// [`AllocationMapError::AfterAllocation`] `-49` ;
+    /// [`AllocationMapError::ExceedsArena`] vaut `-56` moins l'index de la
+    /// catégorie (`-56` pour 8 octets, `-63` pour 1024 octets).
+    pub const fn into_raw(self) -> i16 {
+        match self {
+            Self::AlreadySet => -48,
+            Self::AfterAllocation => -49,
+            Self::ExceedsArena(block_size) => -56 - block_size.index() as i16,
+        }
+    }
+
+    /// Retrouve l'erreur correspondant à un code, ou `None` s'il est inconnu.
+    pub const fn from_raw(code: i16) -> Option<Self> {
+        match code {
+            -48 => Some(Self::AlreadySet),
+            -49 => Some(Self::AfterAllocation),
+            -63..=-56 => match BlockSize::from_index((-56 - code) as usize) {
+                Some(block_size) => Some(Self::ExceedsArena(block_size)),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Retourne une courte description de l'erreur, à la manière de `strerror`.
+    ///
+    /// Disponible uniquement avec la feature `error-strings`.
+    #[cfg(feature = "error-strings")]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AlreadySet => "allocation map already set",
+            Self::AfterAllocation => "blocks allocated before the allocation map",
+            Self::ExceedsArena(_) => "allocation map exceeds arena",
+        }
+    }
+}
+
+#[cfg(feature = "allocation-map")]
+impl From<AllocationMapError> for i16 {
+    fn from(error: AllocationMapError) -> i16 {
+        error.into_raw()
+    }
+}
+
+#[cfg(feature = "allocation-map")]
+impl TryFrom<i16> for AllocationMapError {
+    type Error = i16;
+
+    /// Retrouve l'erreur d'un code, ou retourne le code s'il est inconnu.
+    fn try_from(code: i16) -> Result<Self, i16> {
+        Self::from_raw(code).ok_or(code)
+    }
+}
+
+/// Déclare un plan d'allocation à la compilation.
+///
+/// Chaque couple `(taille, nombre)` autorise `nombre` allocations simultanées
+/// d'exactement `taille` octets. Le plan est installé via
+/// [`SlabMemory::set_allocation_map`](crate::SlabMemory::set_allocation_map).
+///
+/// Disponible uniquement avec la feature `allocation-map`.
+///
+/// # Exemple
+///
+/// ```rust
+/// use global_allocator::{allocation_map, SlabMemory};
+///
+/// allocation_map!(static MAP = [(24, 16), (120, 4), (512, 2)]);
+///
+/// #[repr(align(1024))]
+/// struct Heap([u8; 32 * 1024]);
+///
+/// let heap = Box::leak(Box::new(Heap([0; 32 * 1024])));
+/// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+/// SlabMemory::set_allocation_map(&MAP).unwrap();
+/// ```
+#[cfg(feature = "allocation-map")]
+#[macro_export]
+macro_rules! allocation_map {
+    ($vis:vis static $name:ident = [$(($size:expr, $count:expr)),* $(,)?]) => {
+        $vis static $name: [$crate::AllocationEntry; [$(stringify!($size)),*].len()] =
+            [$($crate::AllocationEntry::new($size, $count)),*];
+    };
+}
+
+/// Début des entrées du plan installé, ou `null` si aucun plan n'est installé.
+#[cfg(feature = "allocation-map")]
+static ENTRIES: AtomicPtr<AllocationEntry> = AtomicPtr::new(ptr::null_mut());
+
+/// Nombre d'entrées du plan installé.
+///
+/// Écrit avant [`ENTRIES`] : un contexte qui lit un plan non nul voit sa longueur.
+#[cfg(feature = "allocation-map")]
+static LEN: AtomicUsize = AtomicUsize::new(0);
+
+/// Indique qu'un plan a été installé ou est en cours d'installation.
+#[cfg(feature = "allocation-map")]
+static CLAIMED: AtomicBool = AtomicBool::new(false);
+
+/// Indique qu'un bloc a été remis sans plan installé, par une allocation ou
+/// une réservation : un plan installé ensuite ne pourrait pas le compter.
+#[cfg(feature = "allocation-map")]
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Nombre d'allocations refusées car hors du plan.
+#[cfg(feature = "allocation-map")]
+static VIOLATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Nombre de libérations d'une taille du plan dont aucune allocation n'était
+/// comptée (bloc libéré avec un autre layout que celui de son allocation).
+#[cfg(feature = "allocation-map")]
+static UNMATCHED: AtomicUsize = AtomicUsize::new(0);
+
+/// Retourne les entrées du plan installé, s'il y en a un.
+#[cfg(feature = "allocation-map")]
+fn entries() -> Option<&'static [AllocationEntry]> {
+    let start = ENTRIES.load(Ordering::SeqCst);
+    if start.is_null() {
+        return None;
+    }
+    // `start` et `LEN` proviennent d'une même tranche `'static`.
+    Some(unsafe { core::slice::from_raw_parts(start, LEN.load(Ordering::Relaxed)) })
+}
+
+/// Marque la remise d'un bloc, puis retourne le plan s'il a été installé
+/// entre-temps.
+///
+/// Avec [`install`], qui publie le plan avant de lire la marque, les accès en
+/// ordre séquentiel garantissent que l'installation voit la marque ou que
+/// l'appelant voit le plan.
+#[cfg(feature = "allocation-map")]
+fn mark_started() -> Option<&'static [AllocationEntry]> {
+    STARTED.store(true, Ordering::SeqCst);
+    entries()
+}
+
+/// Installe le plan d'allocation.
+///
+/// Échoue si un plan a déjà été installé, ou si un bloc a déjà été alloué ou
+/// réservé : sa libération rendrait au plan une place qu'il n'a jamais
+/// accordée.
+#[cfg(feature = "allocation-map")]
+pub(crate) fn install(entries: &'static [AllocationEntry]) -> Result<(), AllocationMapError> {
+    if CLAIMED.swap(true, Ordering::AcqRel) {
+        return Err(AllocationMapError::AlreadySet);
+    }
+    LEN.store(entries.len(), Ordering::Relaxed);
+    ENTRIES.store(entries.as_ptr().cast_mut(), Ordering::SeqCst);
+    if STARTED.load(Ordering::SeqCst) {
+        ENTRIES.store(ptr::null_mut(), Ordering::SeqCst);
+        CLAIMED.store(false, Ordering::Release);
+        return Err(AllocationMapError::AfterAllocation);
+    }
+    Ok(())
+}
+
+/// Vérifie qu'une allocation de `layout` est prévue par le plan et la compte.
+///
+/// Retourne `false`, après l'avoir signalée, si l'allocation sort du plan.
+/// Sans plan installé, toute allocation est acceptée.
+#[cfg(feature = "allocation-map")]
+pub(crate) fn admit(layout: Layout) -> bool {
+    let Some(entries) = entries().or_else(mark_started) else {
+        return true;
+    };
+    let admitted = entries.iter().find(|entry| entry.size == layout.size()).is_some_and(|entry| {
+        entry
+            .live
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| {
+                (live < entry.count).then_some(live + 1)
+            })
+            .is_ok()
+    });
+    if !admitted {
+        VIOLATIONS.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "debug")]
+        let _ = writeln!(
+            DebugWriter,
+            "Allocation of {} bytes outside the allocation map",
+            layout.size()
+        );
+    }
+    admitted
+}
+
+// Sans la feature `allocation-map`, aucun plan ne peut être installé : toute
+// allocation est acceptée et rien n'est compté.
+#[cfg(not(feature = "allocation-map"))]
+pub(crate) fn admit(_layout: Layout) -> bool {
+    true
+}
+
+/// Indique si [`admit`] accepterait une allocation de `layout`, sans la
+/// compter ni la signaler.
+#[cfg(feature = "allocation-map")]
+pub(crate) fn would_admit(layout: Layout) -> bool {
+    let Some(entries) = entries() else {
+        return true;
+    };
+    entries
+        .iter()
+        .find(|entry| entry.size == layout.size())
+        .is_some_and(|entry| entry.live.load(Ordering::Relaxed) < entry.count)
+}
+
+#[cfg(not(feature = "allocation-map"))]
+pub(crate) fn would_admit(_layout: Layout) -> bool {
+    true
+}
+
+/// Rend au plan l'allocation de `layout` comptée par [`admit`].
+///
+/// Le compteur de la taille ne descend jamais sous zéro : une libération sans
+/// allocation comptée, qui ne peut venir que d'un layout différent de celui de
+/// l'allocation, est seulement comptée et signalée.
+#[cfg(feature = "allocation-map")]
+pub(crate) fn release(layout: Layout) {
+    let Some(entry) = entries().and_then(|e| e.iter().find(|entry| entry.size == layout.size()))
+    else {
+        return;
+    };
+    let released = entry
+        .live
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| live.checked_sub(1))
+        .is_ok();
+    if !released {
+        UNMATCHED.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "debug")]
+        let _ = writeln!(
+            DebugWriter,
+            "Release of {} bytes never admitted by the allocation map",
+            layout.size()
+        );
+    }
+}
+
+#[cfg(not(feature = "allocation-map"))]
+pub(crate) fn release(_layout: Layout) {}
+
+/// Indique si une réservation de `block_size` est compatible avec le plan, et
+/// marque la remise de ses blocs.
+///
+/// Les blocs réservés échappent au plan : leur catégorie ne doit contenir
+/// aucune taille du plan, sans quoi leur libération rendrait au plan une
+/// place qu'il n'a jamais accordée.
+#[cfg(all(feature = "allocation-map", feature = "reservations"))]
+pub(crate) fn allows_reservation(block_size: BlockSize) -> bool {
+    let Some(entries) = entries().or_else(mark_started) else {
+        return true;
+    };
+    let class_of = |entry: &AllocationEntry| {
+        BlockSize::categorize(crate::header::block_request(entry.size))
+    };
+    !entries.iter().any(|entry| class_of(entry) == Some(block_size))
+}
+
+#[cfg(all(not(feature = "allocation-map"), feature = "reservations"))]
+pub(crate) fn allows_reservation(_block_size: BlockSize) -> bool {
+    true
+}
+
+/// Retourne le nombre d'allocations refusées car hors du plan.
+#[cfg(feature = "allocation-map")]
+pub(crate) fn violations() -> usize {
+    VIOLATIONS.load(Ordering::Relaxed)
+}
+
+/// Retourne le nombre de libérations sans allocation comptée.
+#[cfg(feature = "allocation-map")]
+pub(crate) fn unmatched_releases() -> usize {
+    UNMATCHED.load(Ordering::Relaxed)
+}
@@ -1,4 +1,25 @@
-use core::ptr;
+#[cfg(feature = "debug")]
+use core::marker::PhantomData;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+use crate::config::ReusePolicy;
+
+/// Nombre de bits de la tête de liste réservés à l'index du bloc.
+///
+/// Les bits restants contiennent l'étiquette anti-ABA. Sur une cible 32 bits,
+/// l'index et l'étiquette ont 16 bits chacun : une arène gère au plus 65 535
+/// blocs, et l'étiquette reprend la même valeur toutes les 65 536
+/// modifications de la liste. Avec la feature `lock-free`, un contexte
+/// interrompu entre sa lecture de la tête et son `compare_exchange` pendant
+/// que les autres modifient la liste un multiple exact de 65 536 fois
+/// retrouve la même tête et n'est pas protégé contre l'ABA.
+const INDEX_BITS: u32 = usize::BITS / 2;
+
+/// Masque extrayant l'index du bloc (plus un) d'une tête de liste.
+///
+/// C'est aussi le nombre maximal de blocs d'une arène.
+pub(crate) const INDEX_MASK: usize = (1 << INDEX_BITS) - 1;
 
 /// Une arène mémoire simple pour gérer les allocations de taille fixe.
 ///
@@ -10,18 +31,51 @@ use core::ptr;
 /// - `start`: Adresse de début de la mémoire gérée.
 /// - `capacity`: Nombre total d'octets dans l'arène.
 /// - `block_size`: Taille de chaque bloc géré.
-/// - `free_list`: Pointeur vers le premier bloc libre.
+/// - `free_list`: Tête étiquetée de la liste des blocs libres.
+/// - `free`: Nombre de blocs libres.
 pub struct Arena {
     /// Pointeur vers le début de la mémoire de l'arène.
-    start: *mut u8,
+    ///
+    /// Tous les blocs sont dérivés de ce pointeur, qui porte la provenance de
+    /// toute la mémoire de l'arène.
+    start: NonNull<u8>,
     /// Capacité totale en octets.
     capacity: usize,
     /// Taille de chaque bloc de mémoire.
     block_size: usize,
-    /// Pointeur vers le premier bloc libre.
-    free_list: *mut FreeNode,
+    /// Tête de la liste des blocs libres.
+    ///
+    /// Les bits de poids faible contiennent l'index du premier bloc libre plus
+    /// un (`0` pour une liste vide), les bits de poids fort une étiquette
+    /// incrémentée à chaque modification. Avec la feature `lock-free`,
+    /// l'étiquette fait échouer le `compare_exchange` d'un contexte dont la
+    /// lecture de la tête est devenue obsolète (problème ABA).
+    free_list: AtomicUsize,
+    /// Nombre de blocs dans la liste des blocs libres.
+    ///
+    /// Avec la feature `lock-free`, incrémenté avant de remettre un bloc et
+    /// décrémenté après en avoir retiré un : la valeur lue peut surestimer
+    /// la liste pendant une opération en cours, jamais la sous-estimer.
+    free: AtomicUsize,
+    /// Dernier bloc de la liste des blocs libres, ou `null` si elle est vide.
+    ///
+    /// Tenu à jour uniquement en [`ReusePolicy::Fifo`], où les blocs libérés
+    /// sont ajoutés en fin de liste ; recalculé au passage dans ce mode.
+    tail: *mut FreeNode,
+    /// Ordre de réutilisation des blocs libérés.
+    ///
+    /// Ne change que via `&mut self` : une arène en [`ReusePolicy::Fifo`]
+    /// n'est jamais modifiée par les méthodes partagées.
+    reuse: ReusePolicy,
 }
 
+// L'arène possède seule la mémoire qu'elle gère : elle peut changer de contexte.
+unsafe impl Send for Arena {}
+
+// Les méthodes partagées de l'arène ne modifient la liste que par `compare_exchange`.
+#[cfg(feature = "lock-free")]
+unsafe impl Sync for Arena {}
+
 /// Un nœud de la liste chaînée des blocs libres.
 ///
 /// Chaque bloc libre contient un pointeur vers le bloc suivant, ou `null` s'il n'y en a pas.
@@ -31,6 +85,75 @@ struct FreeNode {
     next: *mut FreeNode,
 }
 
+/// Chaîne de blocs libres préparée hors de l'arène.
+///
+/// Les blocs sont reliés entre eux au fil de [`FreeChain::push`], sans tenir
+/// le verrou de l'arène ; [`Arena::splice`] raccorde ensuite toute la chaîne
+/// à la liste des blocs libres en temps constant.
+pub struct FreeChain {
+    /// Premier nœud de la chaîne, ou `null` si elle est vide.
+    first: *mut FreeNode,
+    /// Dernier nœud de la chaîne, dont le lien reste à raccorder.
+    last: *mut FreeNode,
+    /// Nombre de blocs de la chaîne.
+    len: usize,
+}
+
+impl FreeChain {
+    /// Crée une chaîne vide.
+    pub const fn new() -> Self {
+        Self {
+            first: ptr::null_mut(),
+            last: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    /// Ajoute un bloc en tête de la chaîne.
+    ///
+    /// # Safety
+    ///
+    /// `block` doit être un bloc alloué de l'arène à laquelle la chaîne sera
+    /// raccordée, et ne plus être utilisé par l'appelant.
+    pub unsafe fn push(&mut self, block: NonNull<u8>) {
+        let node = block.cast::<FreeNode>().as_ptr();
+        unsafe { (*node).next = self.first };
+        if self.last.is_null() {
+            self.last = node;
+        }
+        self.first = node;
+        self.len += 1;
+    }
+}
+
+/// Itérateur sur les adresses des blocs libres d'une arène.
+///
+/// Parcourt la liste chaînée des blocs libres dans l'ordre où ils seraient
+/// alloués. Obtenu via [`Arena::free_blocks`].
+#[cfg(feature = "debug")]
+pub struct FreeBlocks<'a> {
+    /// Prochain nœud à visiter, ou `null` en fin de liste.
+    current: *mut FreeNode,
+    /// Lie l'itérateur à l'emprunt de l'arène parcourue.
+    _arena: PhantomData<&'a Arena>,
+}
+
+#[cfg(feature = "debug")]
+impl Iterator for FreeBlocks<'_> {
+    type Item = *mut u8;
+
+    /// Retourne l'adresse du prochain bloc libre.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_null() {
+            return None;
+        }
+        let node = self.current;
+        // L'arène est empruntée : sa liste ne peut pas changer pendant le parcours.
+        self.current = unsafe { (*node).next };
+        Some(node.cast())
+    }
+}
+
 impl Arena {
     /// Crée une nouvelle arène mémoire.
     ///
@@ -40,6 +163,9 @@ impl Arena {
     /// - `capacity`: Capacité totale de la mémoire (en octets).
     /// - `block_size`: Taille de chaque bloc géré.
     ///
+    /// Une arène gère au plus [`INDEX_MASK`] blocs (`2^(usize::BITS / 2) - 1`,
+    /// soit 65 535 sur une cible 32 bits) ; la mémoire au-delà est ignorée.
+    ///
     /// # Safety
     ///
     /// L'appelant doit s'assurer que la mémoire pointée par `start` est valide
@@ -47,21 +173,25 @@ impl Arena {
     ///
     /// # Exemple
     ///
-    /// ```rust
+    /// ```rust,ignore
+    /// use core::ptr::NonNull;
     /// use my_allocator::Arena;
     /// unsafe {
     ///     let mut buffer = [0u8; 1024];
-    ///     let arena = Arena::new(buffer.as_mut_ptr(), 1024, 32);
+    ///     let arena = Arena::new(NonNull::from(&mut buffer).cast(), 1024, 32);
     /// }
     /// ```
-    pub unsafe fn new(start: *mut u8, capacity: usize, block_size: usize) -> Self {
+    pub unsafe fn new(start: NonNull<u8>, capacity: usize, block_size: usize) -> Self {
         let mut arena = Self {
             start,
             capacity,
             block_size,
-            free_list: ptr::null_mut(),
+            free_list: AtomicUsize::new(0),
+            free: AtomicUsize::new(0),
+            tail: ptr::null_mut(),
+            reuse: ReusePolicy::Lifo,
         };
-        arena.initialize_free_list();
+        unsafe { arena.initialize_free_list() };
         arena
     }
 
@@ -75,22 +205,54 @@ impl Arena {
     /// Cette méthode modifie directement la mémoire pointée par `start`. Elle doit
     /// être appelée uniquement lorsque l'arène est correctement configurée.
     unsafe fn initialize_free_list(&mut self) {
+        let count = self.block_count();
         let mut current = self.start;
-        for _ in 0..self.capacity / self.block_size {
-            let next = current.add(self.block_size);
-            (*(current as *mut FreeNode)).next = if next < self.start.add(self.capacity) {
-                next as *mut FreeNode
-            } else {
-                ptr::null_mut()
-            };
+        for i in 0..count {
+            let next = unsafe { current.byte_add(self.block_size) };
+            let link = if i + 1 < count { next.cast().as_ptr() } else { ptr::null_mut() };
+            unsafe { (*current.cast::<FreeNode>().as_ptr()).next = link };
+            self.tail = current.cast().as_ptr();
             current = next;
         }
-        self.free_list = self.start as *mut FreeNode;
+        *self.free_list.get_mut() = self.head_of(self.first_node(), 0);
+        *self.free.get_mut() = count;
+    }
+
+    /// Retourne le premier bloc de l'arène, ou `null` si elle n'a aucun bloc.
+    fn first_node(&self) -> *mut FreeNode {
+        if self.block_count() == 0 {
+            ptr::null_mut()
+        } else {
+            self.start.cast().as_ptr()
+        }
+    }
+
+    /// Retourne le bloc désigné par une tête de liste, ou `null` si elle est vide.
+    fn node_of(&self, head: usize) -> *mut FreeNode {
+        match head & INDEX_MASK {
+            0 => ptr::null_mut(),
+            slot => self.start.as_ptr().wrapping_add((slot - 1) * self.block_size).cast(),
+        }
+    }
+
+    /// Construit une tête de liste désignant `node` avec l'étiquette `tag`.
+    fn head_of(&self, node: *mut FreeNode, tag: usize) -> usize {
+        let slot = if node.is_null() {
+            0
+        } else {
+            node.addr().wrapping_sub(self.start.as_ptr().addr()) / self.block_size + 1
+        };
+        (tag << INDEX_BITS) | slot
+    }
+
+    /// Retourne l'étiquette suivant celle d'une tête de liste.
+    fn next_tag(head: usize) -> usize {
+        (head >> INDEX_BITS).wrapping_add(1)
     }
 
     /// Alloue un bloc de mémoire depuis l'arène.
     ///
-    /// Retourne un pointeur vers un bloc libre, ou `null_mut` si l'arène est pleine.
+    /// Retourne un pointeur vers un bloc libre, ou `None` si l'arène est pleine.
     ///
     /// # Safety
     ///
@@ -99,22 +261,60 @@ impl Arena {
     ///
     /// # Exemple
     ///
-    /// ```rust
+    /// ```rust,ignore
+    /// use core::ptr::NonNull;
     /// use my_allocator::Arena;
     /// unsafe {
     ///     let mut buffer = [0u8; 1024];
-    ///     let mut arena = Arena::new(buffer.as_mut_ptr(), 1024, 32);
+    ///     let mut arena = Arena::new(NonNull::from(&mut buffer).cast(), 1024, 32);
     ///     let ptr = arena.allocate();
-    ///     assert!(!ptr.is_null());
+    ///     assert!(ptr.is_some());
+    /// }
+    /// ```
+    pub unsafe fn allocate(&mut self) -> Option<NonNull<u8>> {
+        let head = *self.free_list.get_mut();
+        let node = NonNull::new(self.node_of(head))?;
+        let next = unsafe { (*node.as_ptr()).next };
+        *self.free_list.get_mut() = self.head_of(next, Self::next_tag(head));
+        *self.free.get_mut() -= 1;
+        if next.is_null() {
+            self.tail = ptr::null_mut();
+        }
+        Some(node.cast())
+    }
+
+    /// Alloue jusqu'à `out.len()` blocs d'un coup.
+    ///
+    /// S'arrête au premier échec si l'arène se vide en cours de route.
+    ///
+    /// # Returns
+    ///
+    /// Le nombre de blocs écrits au début de `out`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::allocate`] pour chaque bloc retourné.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust,ignore
+    /// use core::ptr::NonNull;
+    /// use my_allocator::Arena;
+    /// unsafe {
+    ///     let mut buffer = [0u8; 1024];
+    ///     let mut arena = Arena::new(NonNull::from(&mut buffer).cast(), 1024, 32);
+    ///     let mut blocks = [NonNull::dangling(); 4];
+    ///     assert_eq!(arena.allocate_many(&mut blocks), 4);
     /// }
     /// ```
-    pub unsafe fn allocate(&mut self) -> *mut u8 {
-        if self.free_list.is_null() {
-            return ptr::null_mut();
+    pub unsafe fn allocate_many(&mut self, out: &mut [NonNull<u8>]) -> usize {
+        for (count, slot) in out.iter_mut().enumerate() {
+            match unsafe { self.allocate() } {
+                Some(block) => *slot = block,
+                None => return count,
+            }
         }
-        let node = self.free_list;
-        self.free_list = (*node).next;
-        node as *mut u8
+        out.len()
     }
 
     /// Désalloue un bloc de mémoire et le remet dans la liste des blocs libres.
@@ -130,18 +330,307 @@ impl Arena {
     ///
     /// # Exemple
     ///
-    /// ```rust
+    /// ```rust,ignore
+    /// use core::ptr::NonNull;
     /// use my_allocator::Arena;
     /// unsafe {
     ///     let mut buffer = [0u8; 1024];
-    ///     let mut arena = Arena::new(buffer.as_mut_ptr(), 1024, 32);
-    ///     let ptr = arena.allocate();
+    ///     let mut arena = Arena::new(NonNull::from(&mut buffer).cast(), 1024, 32);
+    ///     let ptr = arena.allocate().unwrap();
     ///     arena.deallocate(ptr);
     /// }
     /// ```
-    pub unsafe fn deallocate(&mut self, ptr: *mut u8) {
-        let node = ptr as *mut FreeNode;
-        (*node).next = self.free_list;
-        self.free_list = node;
+    pub unsafe fn deallocate(&mut self, ptr: NonNull<u8>) {
+        let node = ptr.cast::<FreeNode>().as_ptr();
+        if self.reuse == ReusePolicy::Fifo {
+            unsafe { (*node).next = ptr::null_mut() };
+            unsafe { self.append(node, node) };
+        } else {
+            let head = *self.free_list.get_mut();
+            unsafe { (*node).next = self.node_of(head) };
+            *self.free_list.get_mut() = self.head_of(node, Self::next_tag(head));
+        }
+        *self.free.get_mut() += 1;
+    }
+
+    /// Ajoute les nœuds reliés de `first` à `last` en fin de liste.
+    ///
+    /// # Safety
+    ///
+    /// Le lien de `last` doit être `null`, et l'arène en
+    /// [`ReusePolicy::Fifo`] pour que sa fin de liste soit à jour.
+    unsafe fn append(&mut self, first: *mut FreeNode, last: *mut FreeNode) {
+        if self.tail.is_null() {
+            let head = *self.free_list.get_mut();
+            *self.free_list.get_mut() = self.head_of(first, Self::next_tag(head));
+        } else {
+            unsafe { (*self.tail).next = first };
+        }
+        self.tail = last;
+    }
+
+    /// Raccorde une chaîne de blocs libérés en tête de la liste des blocs
+    /// libres, ou en fin de liste en [`ReusePolicy::Fifo`].
+    ///
+    /// L'opération ne touche que le dernier nœud de la chaîne et une
+    /// extrémité de la liste, quelle que soit la longueur de la chaîne.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::deallocate`] pour chaque bloc de `chain`.
+    pub unsafe fn splice(&mut self, chain: FreeChain) {
+        if chain.len == 0 {
+            return;
+        }
+        if self.reuse == ReusePolicy::Fifo {
+            unsafe { (*chain.last).next = ptr::null_mut() };
+            unsafe { self.append(chain.first, chain.last) };
+        } else {
+            let head = *self.free_list.get_mut();
+            unsafe { (*chain.last).next = self.node_of(head) };
+            *self.free_list.get_mut() = self.head_of(chain.first, Self::next_tag(head));
+        }
+        *self.free.get_mut() += chain.len;
+    }
+
+    /// Alloue un bloc sans exclusion mutuelle, par `compare_exchange` sur la tête.
+    ///
+    /// Plusieurs contextes (tâches, interruptions) peuvent appeler cette méthode
+    /// en même temps sur la même arène. Un contexte interrompu au milieu d'une
+    /// allocation recommence simplement sa tentative.
+    ///
+    /// Disponible uniquement avec la feature `lock-free`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::allocate`]. L'arène ne doit pas être
+    /// modifiée via `&mut self` pendant l'appel, et doit être en
+    /// [`ReusePolicy::Lifo`].
+    #[cfg(feature = "lock-free")]
+    pub unsafe fn allocate_shared(&self) -> Option<NonNull<u8>> {
+        let mut head = self.free_list.load(Ordering::Acquire);
+        loop {
+            let node = NonNull::new(self.node_of(head))?;
+            // Le bloc peut avoir été alloué entre-temps : la valeur lue est alors
+            // périmée, mais l'étiquette fait échouer l'échange ci-dessous.
+            let next = unsafe { ptr::read_volatile(&(*node.as_ptr()).next) };
+            let new_head = self.head_of(next, Self::next_tag(head));
+            match self.free_list.compare_exchange_weak(
+                head,
+                new_head,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    self.free.fetch_sub(1, Ordering::Relaxed);
+                    return Some(node.cast());
+                }
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Alloue jusqu'à `out.len()` blocs sans exclusion mutuelle.
+    ///
+    /// Chaque bloc est retiré par son propre `compare_exchange` : d'autres
+    /// contextes peuvent allouer dans la même arène entre deux blocs.
+    ///
+    /// Disponible uniquement avec la feature `lock-free`.
+    ///
+    /// # Returns
+    ///
+    /// Le nombre de blocs écrits au début de `out`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::allocate_shared`].
+    #[cfg(feature = "lock-free")]
+    pub unsafe fn allocate_many_shared(&self, out: &mut [NonNull<u8>]) -> usize {
+        for (count, slot) in out.iter_mut().enumerate() {
+            match unsafe { self.allocate_shared() } {
+                Some(block) => *slot = block,
+                None => return count,
+            }
+        }
+        out.len()
+    }
+
+    /// Remet un bloc dans la liste sans exclusion mutuelle.
+    ///
+    /// Disponible uniquement avec la feature `lock-free`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::deallocate`]. L'arène ne doit pas être
+    /// modifiée via `&mut self` pendant l'appel, et doit être en
+    /// [`ReusePolicy::Lifo`].
+    #[cfg(feature = "lock-free")]
+    pub unsafe fn deallocate_shared(&self, ptr: NonNull<u8>) {
+        let node = ptr.cast::<FreeNode>().as_ptr();
+        self.free.fetch_add(1, Ordering::Relaxed);
+        let mut head = self.free_list.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*node).next = self.node_of(head) };
+            let new_head = self.head_of(node, Self::next_tag(head));
+            match self.free_list.compare_exchange_weak(
+                head,
+                new_head,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Raccorde une chaîne de blocs libérés sans exclusion mutuelle.
+    ///
+    /// Toute la chaîne est publiée par un seul `compare_exchange` réussi.
+    ///
+    /// Disponible uniquement avec la feature `lock-free`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::deallocate_shared`] pour chaque bloc de
+    /// `chain`.
+    #[cfg(feature = "lock-free")]
+    pub unsafe fn splice_shared(&self, chain: FreeChain) {
+        if chain.len == 0 {
+            return;
+        }
+        self.free.fetch_add(chain.len, Ordering::Relaxed);
+        let mut head = self.free_list.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*chain.last).next = self.node_of(head) };
+            let new_head = self.head_of(chain.first, Self::next_tag(head));
+            match self.free_list.compare_exchange_weak(
+                head,
+                new_head,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Retourne l'ordre de réutilisation des blocs libérés.
+    #[cfg(feature = "lock-free")]
+    pub fn reuse_policy(&self) -> ReusePolicy {
+        self.reuse
+    }
+
+    /// Change l'ordre de réutilisation des blocs libérés.
+    ///
+    /// Les blocs déjà libres gardent leur ordre. Le passage en
+    /// [`ReusePolicy::Fifo`] parcourt la liste pour en retrouver la fin :
+    /// linéaire en nombre de blocs libres.
+    pub fn set_reuse_policy(&mut self, policy: ReusePolicy) {
+        if policy == ReusePolicy::Fifo && self.reuse != policy {
+            let head = *self.free_list.get_mut();
+            let mut node = self.node_of(head);
+            self.tail = ptr::null_mut();
+            while !node.is_null() {
+                self.tail = node;
+                // Le nœud est un bloc libre de l'arène : il peut être lu.
+                node = unsafe { (*node).next };
+            }
+        }
+        self.reuse = policy;
+    }
+
+    /// Retourne l'adresse de début de la mémoire gérée par l'arène.
+    #[cfg(any(feature = "debug", feature = "gdb-symbols"))]
+    pub fn start(&self) -> NonNull<u8> {
+        self.start
+    }
+
+    /// Retourne la taille de chaque bloc géré, en octets.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Retourne le nombre total de blocs de l'arène.
+    pub fn block_count(&self) -> usize {
+        (self.capacity / self.block_size).min(INDEX_MASK)
+    }
+
+    /// Retourne le nombre de blocs libres de l'arène.
+    pub fn free_count(&self) -> usize {
+        self.free.load(Ordering::Relaxed)
+    }
+
+    /// Indique si `ptr` désigne le début d'un bloc de cette arène.
+    pub fn contains(&self, ptr: *mut u8) -> bool {
+        let offset = ptr.addr().wrapping_sub(self.start.as_ptr().addr());
+        offset < self.block_count() * self.block_size && offset.is_multiple_of(self.block_size)
+    }
+
+    /// Vérifie la cohérence de la liste des blocs libres.
+    ///
+    /// Chaque nœud doit être le début d'un bloc de l'arène, la liste ne doit
+    /// pas boucler et sa longueur doit être égale au compteur de blocs libres.
+    /// Le parcours est linéaire en nombre de blocs libres.
+    ///
+    /// Disponible uniquement avec la feature `paranoid`.
+    ///
+    /// # Returns
+    ///
+    /// Le premier nœud invalide, ou le début de l'arène si seul le compteur
+    /// est faux.
+    #[cfg(feature = "paranoid")]
+    pub fn check(&self) -> Result<(), NonNull<u8>> {
+        let mut node = self.node_of(self.free_list.load(Ordering::Acquire));
+        let mut count = 0;
+        while let Some(current) = NonNull::new(node) {
+            if !self.contains(current.as_ptr().cast()) || count == self.block_count() {
+                return Err(current.cast());
+            }
+            count += 1;
+            // Le nœud est un bloc de l'arène : il peut être lu.
+            node = unsafe { (*current.as_ptr()).next };
+        }
+        if count != self.free_count() {
+            return Err(self.start);
+        }
+        Ok(())
+    }
+
+    /// Retourne l'adresse du mot de tête de la liste des blocs libres.
+    ///
+    /// Utilisé pour exporter l'état de l'arène vers un débogueur.
+    #[cfg(feature = "gdb-symbols")]
+    pub fn free_list_head(&self) -> *const AtomicUsize {
+        &self.free_list
+    }
+
+    /// Retourne un itérateur sur les adresses des blocs libres de l'arène.
+    ///
+    /// Les adresses sont produites dans l'ordre de la liste chaînée, c'est-à-dire
+    /// dans l'ordre où les prochaines allocations les retourneraient.
+    ///
+    /// Disponible uniquement avec la feature `debug`.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust,ignore
+    /// use core::ptr::NonNull;
+    /// use my_allocator::Arena;
+    /// unsafe {
+    ///     let mut buffer = [0u8; 1024];
+    ///     let mut arena = Arena::new(NonNull::from(&mut buffer).cast(), 1024, 32);
+    ///     let ptr = arena.allocate().unwrap().as_ptr();
+    ///     assert_eq!(arena.free_blocks().count(), 31);
+    ///     assert!(arena.free_blocks().all(|block| block != ptr));
+    /// }
+    /// ```
+    #[cfg(feature = "debug")]
+    pub fn free_blocks(&self) -> FreeBlocks<'_> {
+        FreeBlocks {
+            current: self.node_of(self.free_list.load(Ordering::Acquire)),
+            _arena: PhantomData,
+        }
     }
 }
@@ -0,0 +1,206 @@
+//! Charge de test de l'allocateur avec mesure des durées, à exécuter sur la
+//! cible comme test de recette.
+//!
+//! [`stress`] remplit chaque catégorie demandée jusqu'à un taux de charge,
+//! puis alterne libérations et allocations d'une catégorie à l'autre en
+//! mesurant chaque opération avec le compteur fourni. Le rapport indique la
+//! durée maximale observée et le nombre d'échecs, qui doit être nul tant que
+//! le taux de charge reste sous la capacité des arènes.
+//!
+//! Disponible uniquement avec la feature `bench`.
+//!
+//! ```rust
+//! use global_allocator::bench::{self, StressConfig};
+//! use global_allocator::{BlockSize, SlabMemory};
+//!
+//! #[repr(align(1024))]
+//! struct Heap([u8; 16 * 1024]);
+//!
+//! let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+//! unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+//!
+//! fn cycles() -> u32 {
+//!     0 // Lire le compteur de cycles de la cible.
+//! }
+//!
+//! let report = bench::stress(&StressConfig {
+//!     classes: &[BlockSize::Small, BlockSize::Huge],
+//!     load_percent: 75,
+//!     rounds: 1_000,
+//!     clock: cycles,
+//! });
+//! assert!(report.meets(10_000));
+//! ```
+
+use core::alloc::Layout;
+use core::mem::size_of;
+use core::ptr::{self, NonNull};
+use crate::config::BlockSize;
+use crate::header::HEADER_SIZE;
+use crate::memory::SlabMemory;
+
+/// Paramètres d'une charge de test.
+#[derive(Copy, Clone, Debug)]
+pub struct StressConfig<'a> {
+    /// Catégories sollicitées, dans l'ordre où elles alternent.
+    pub classes: &'a [BlockSize],
+    /// Part des blocs libres de chaque catégorie gardés alloués pendant le
+    /// test, en pourcentage.
+    pub load_percent: usize,
+    /// Nombre de tours ; chaque tour libère puis réalloue un bloc dans
+    /// chaque catégorie.
+    pub rounds: usize,
+    /// Compteur monotone fourni par l'utilisateur, par exemple `DWT::CYCCNT`.
+    /// Son débordement est géré.
+    pub clock: fn() -> u32,
+}
+
+/// Résultat d'une charge de test.
+///
+/// Les tableaux sont indexés comme les catégories (voir [`BlockSize::index`]).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StressReport {
+    /// Nombre d'allocations réussies.
+    pub allocations: usize,
+    /// Nombre d'allocations échouées.
+    pub failures: usize,
+    /// Durée maximale d'une allocation, par catégorie.
+    pub max_allocate: [u32; BlockSize::COUNT],
+    /// Durée maximale d'une libération, par catégorie.
+    pub max_deallocate: [u32; BlockSize::COUNT],
+}
+
+impl StressReport {
+    /// Retourne la plus longue opération observée, toutes catégories confondues.
+    pub fn max_latency(&self) -> u32 {
+        self.max_allocate.iter().chain(&self.max_deallocate).copied().max().unwrap_or(0)
+    }
+
+    /// Indique si le test est réussi : aucun échec et aucune opération plus
+    /// longue que `deadline`.
+    pub fn meets(&self, deadline: u32) -> bool {
+        self.failures == 0 && self.max_latency() <= deadline
+    }
+}
+
+/// Blocs alloués d'une catégorie, du plus ancien au plus récent.
+///
+/// La file est chaînée dans les blocs eux-mêmes, pour ne rien allouer en
+/// dehors de la charge mesurée.
+struct LiveQueue {
+    /// Bloc alloué depuis le plus longtemps.
+    head: Option<NonNull<u8>>,
+    /// Dernier bloc alloué.
+    tail: Option<NonNull<u8>>,
+}
+
+impl LiveQueue {
+    /// Ajoute un bloc en fin de file.
+    ///
+    /// # Safety
+    ///
+    /// `block` doit être alloué et pouvoir contenir un pointeur aligné.
+    unsafe fn push(&mut self, block: NonNull<u8>) {
+        unsafe { block.cast::<*mut u8>().write(ptr::null_mut()) };
+        match self.tail {
+            Some(tail) => unsafe { tail.cast::<*mut u8>().write(block.as_ptr()) },
+            None => self.head = Some(block),
+        }
+        self.tail = Some(block);
+    }
+
+    /// Retire le bloc le plus ancien.
+    fn pop(&mut self) -> Option<NonNull<u8>> {
+        let block = self.head?;
+        self.head = NonNull::new(unsafe { block.cast::<*mut u8>().read() });
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        Some(block)
+    }
+}
+
+/// Mesure une opération et met à jour la durée maximale `max`.
+fn timed<R>(clock: fn() -> u32, max: &mut u32, operation: impl FnOnce() -> R) -> R {
+    let start = clock();
+    let result = operation();
+    *max = (*max).max(clock().wrapping_sub(start));
+    result
+}
+
+/// Exécute une charge de test et retourne ses mesures.
+///
+/// Chaque catégorie est d'abord remplie jusqu'à `load_percent` de ses blocs
+/// libres, puis les tours alternent d'une catégorie à l'autre : le bloc le
+/// plus ancien est libéré et un nouveau bloc est alloué. Tous les blocs sont
+/// libérés à la fin, de sorte que le tas retrouve son état initial.
+///
+/// Les catégories trop petites pour chaîner leurs blocs (moins d'un
+/// pointeur utile, feature `block-header`) sont ignorées.
+///
+/// Plusieurs charges peuvent s'exécuter en même temps sur des catégories
+/// distinctes, par exemple une par tâche.
+pub fn stress(config: &StressConfig<'_>) -> StressReport {
+    let mut report = StressReport::default();
+    let mut queues = [const { LiveQueue { head: None, tail: None } }; BlockSize::COUNT];
+    let layout = |block_size: BlockSize| {
+        Layout::from_size_align(block_size.size() - HEADER_SIZE, SlabMemory::MIN_ALIGN).ok()
+    };
+    let classes = || {
+        config
+            .classes
+            .iter()
+            .copied()
+            .filter(|block_size| block_size.size() - HEADER_SIZE >= size_of::<*mut u8>())
+    };
+
+    let allocate = |report: &mut StressReport, queues: &mut [LiveQueue], block_size: BlockSize| {
+        let index = block_size.index();
+        let Some(layout) = layout(block_size) else {
+            return false;
+        };
+        let block = timed(config.clock, &mut report.max_allocate[index], || unsafe {
+            SlabMemory::allocate_non_null(layout)
+        });
+        match block {
+            Some(block) => {
+                report.allocations += 1;
+                unsafe { queues[index].push(block) };
+                true
+            }
+            None => {
+                report.failures += 1;
+                false
+            }
+        }
+    };
+    let deallocate = |report: &mut StressReport, queues: &mut [LiveQueue], block_size: BlockSize| {
+        let index = block_size.index();
+        if let (Some(block), Some(layout)) = (queues[index].pop(), layout(block_size)) {
+            timed(config.clock, &mut report.max_deallocate[index], || unsafe {
+                SlabMemory::deallocate_non_null(block, layout)
+            });
+        }
+    };
+
+    for block_size in classes() {
+        let target = SlabMemory::available(block_size) * config.load_percent / 100;
+        for _ in 0..target {
+            if !allocate(&mut report, &mut queues, block_size) {
+                break;
+            }
+        }
+    }
+    for _ in 0..config.rounds {
+        for block_size in classes() {
+            deallocate(&mut report, &mut queues, block_size);
+            allocate(&mut report, &mut queues, block_size);
+        }
+    }
+    for block_size in classes() {
+        while queues[block_size.index()].head.is_some() {
+            deallocate(&mut report, &mut queues, block_size);
+        }
+    }
+    report
+}
@@ -0,0 +1,297 @@
+#[cfg(feature = "caller-tags")]
+use core::fmt;
+#[cfg(feature = "caller-tags")]
+use core::mem;
+#[cfg(feature = "caller-tags")]
+use core::ptr;
+use core::ptr::NonNull;
+#[cfg(feature = "caller-tags")]
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicU8, AtomicUsize, Ordering};
+#[cfg(feature = "caller-tags")]
+use crate::config::BlockSize;
+#[cfg(feature = "caller-tags")]
+use crate::utils::env_usize;
+
+/// Nombre maximal de blocs vivants dont l'appelant est enregistré.
+///
+/// Vaut 64 par défaut. Fixé à la compilation par la variable d'environnement
+/// `SLAB_TRACKED_CALLERS` (par exemple `SLAB_TRACKED_CALLERS=16 cargo
+/// build`) : chaque emplacement occupe trois mots de RAM statique. Le
+/// comportement lorsque la table est pleine est choisi par
+/// [`TrackingOverflow`].
+#[cfg(feature = "caller-tags")]
+pub const MAX_TRACKED_CALLERS: usize = env_usize!("SLAB_TRACKED_CALLERS", 64);
+
+/// Réaction de la table des appelants lorsqu'elle est pleine.
+///
+/// Installée via
+/// [`SlabMemory::set_tracking_overflow`](crate::SlabMemory::set_tracking_overflow).
+/// Dans tous les cas, chaque allocation non enregistrée ou retirée de la
+/// table est comptée dans la ligne `untracked` du rapport de fuites, y
+/// compris après sa libération : ce compteur est cumulé depuis le démarrage.
+///
+/// Disponible uniquement avec la feature `caller-tags`.
+#[cfg(feature = "caller-tags")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TrackingOverflow {
+    /// La nouvelle allocation n'est pas enregistrée ; elle le sera dès qu'un
+    /// emplacement se libère.
+    #[default]
+    DropNewest = 0,
+    /// Le bloc enregistré depuis le plus longtemps est retiré de la table au
+    /// profit de la nouvelle allocation : le rapport montre les allocations
+    /// récentes, où se trouvent le plus souvent les fuites d'un régime
+    /// établi.
+    DropOldest = 1,
+    /// L'enregistrement s'arrête à la première allocation qui ne trouve pas
+    /// de place : les allocations suivantes ne parcourent plus la table.
+    /// Réactivé par un nouvel appel à
+    /// [`SlabMemory::set_tracking_overflow`](crate::SlabMemory::set_tracking_overflow).
+    Disable = 2,
+}
+
+/// Valeur de [`Slot::block`] pendant qu'un emplacement est en cours d'écriture.
+#[cfg(feature = "caller-tags")]
+const CLAIMED: usize = 1;
+
+/// Bloc vivant et identifiant de l'appelant qui l'a alloué.
+///
+/// Disponible uniquement avec la feature `caller-tags`.
+#[cfg(feature = "caller-tags")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LiveBlock {
+    /// Pointeur remis à l'appelant.
+    pub ptr: NonNull<u8>,
+    /// Catégorie du bloc.
+    pub block_size: BlockSize,
+    /// Identifiant retourné par le fournisseur au moment de l'allocation.
+    pub caller: usize,
+}
+
+/// Emplacement de la table des appelants.
+#[cfg(feature = "caller-tags")]
+struct Slot {
+    /// Adresse du pointeur remis, `0` si l'emplacement est libre, [`CLAIMED`]
+    /// pendant son écriture.
+    block: AtomicUsize,
+    /// Identifiant de l'appelant.
+    caller: AtomicUsize,
+    /// Numéro d'ordre de l'enregistrement, pour [`TrackingOverflow::DropOldest`].
+    order: AtomicUsize,
+}
+
+/// Table des blocs vivants enregistrés.
+///
+/// Sans verrou : un emplacement est réservé par échange atomique, ce qui
+/// permet d'enregistrer une allocation faite depuis une interruption.
+#[cfg(feature = "caller-tags")]
+static SLOTS: [Slot; MAX_TRACKED_CALLERS] = [const {
+    Slot {
+        block: AtomicUsize::new(0),
+        caller: AtomicUsize::new(0),
+        order: AtomicUsize::new(0),
+    }
+}; MAX_TRACKED_CALLERS];
+
+/// Fournisseur d'identifiant d'appelant, ou `null` si aucun n'est installé.
+#[cfg(feature = "caller-tags")]
+static PROVIDER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Nombre cumulé d'allocations non enregistrées faute d'emplacement libre.
+///
+/// Il ne diminue jamais : ces blocs sont absents de la table et leur
+/// libération ne peut pas être reconnue.
+#[cfg(feature = "caller-tags")]
+static UNTRACKED: AtomicUsize = AtomicUsize::new(0);
+
+/// Politique active, valeur `repr(u8)` d'une [`TrackingOverflow`].
+#[cfg(feature = "caller-tags")]
+static OVERFLOW: AtomicU8 = AtomicU8::new(TrackingOverflow::DropNewest as u8);
+
+/// Indique que l'enregistrement a été arrêté par [`TrackingOverflow::Disable`].
+#[cfg(feature = "caller-tags")]
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Numéro d'ordre du prochain enregistrement.
+#[cfg(feature = "caller-tags")]
+static NEXT_ORDER: AtomicUsize = AtomicUsize::new(0);
+
+/// Installe ou retire le fournisseur d'identifiant d'appelant.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn install(provider: Option<fn() -> usize>) {
+    let provider = provider.map_or(ptr::null_mut(), |provider| provider as *mut ());
+    PROVIDER.store(provider, Ordering::Release);
+}
+
+/// Installe la politique appliquée lorsque la table est pleine et réactive
+/// l'enregistrement.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn set_overflow(policy: TrackingOverflow) {
+    OVERFLOW.store(policy as u8, Ordering::Relaxed);
+    DISABLED.store(false, Ordering::Relaxed);
+}
+
+/// Enregistre l'appelant d'un bloc qui vient d'être alloué.
+///
+/// Sans fournisseur installé, rien n'est enregistré.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn record(ptr: NonNull<u8>) {
+    let provider = PROVIDER.load(Ordering::Acquire);
+    if provider.is_null() {
+        return;
+    }
+    if DISABLED.load(Ordering::Relaxed) {
+        UNTRACKED.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+    // Seules des fonctions `fn() -> usize` sont stockées dans `PROVIDER`.
+    let provider = unsafe { mem::transmute::<*mut (), fn() -> usize>(provider) };
+    let caller = provider();
+    let free = SLOTS.iter().find(|slot| {
+        slot.block
+            .compare_exchange(0, CLAIMED, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    });
+    let slot = match (free, OVERFLOW.load(Ordering::Relaxed)) {
+        (Some(slot), _) => slot,
+        (None, 1) => match evict_oldest() {
+            Some(slot) => slot,
+            None => return,
+        },
+        (None, policy) => {
+            if policy == TrackingOverflow::Disable as u8 {
+                DISABLED.store(true, Ordering::Relaxed);
+            }
+            UNTRACKED.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+    };
+    slot.caller.store(caller, Ordering::Relaxed);
+    slot.order.store(NEXT_ORDER.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
+    slot.block.store(ptr.as_ptr().addr(), Ordering::Release);
+}
+
+// Sans la feature `caller-tags`, la table n'est pas compilée et aucun
+// appelant n'est enregistré.
+#[cfg(not(feature = "caller-tags"))]
+pub(crate) fn record(_ptr: NonNull<u8>) {}
+
+/// Retire de la table le bloc enregistré depuis le plus longtemps et
+/// retourne son emplacement, réservé.
+///
+/// Le bloc retiré est compté comme non enregistré. Si l'emplacement change
+/// entre sa sélection et sa réservation (libération ou enregistrement
+/// concurrent), la nouvelle allocation est comptée à sa place.
+#[cfg(feature = "caller-tags")]
+fn evict_oldest() -> Option<&'static Slot> {
+    let now = NEXT_ORDER.load(Ordering::Relaxed);
+    let oldest = SLOTS
+        .iter()
+        .map(|slot| (slot, slot.block.load(Ordering::Acquire)))
+        .filter(|&(_, block)| block > CLAIMED)
+        .max_by_key(|(slot, _)| now.wrapping_sub(slot.order.load(Ordering::Relaxed)));
+    UNTRACKED.fetch_add(1, Ordering::Relaxed);
+    let (slot, block) = oldest?;
+    slot.block
+        .compare_exchange(block, CLAIMED, Ordering::Acquire, Ordering::Relaxed)
+        .ok()
+        .map(|_| slot)
+}
+
+/// Retire de la table un bloc en cours de libération.
+///
+/// L'emplacement n'est libéré que s'il contient encore ce bloc : s'il a été
+/// repris entre-temps (éviction par [`TrackingOverflow::DropOldest`]), il
+/// n'est pas touché.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn forget(ptr: NonNull<u8>) {
+    let address = ptr.as_ptr().addr();
+    if let Some(slot) = SLOTS.iter().find(|slot| slot.block.load(Ordering::Relaxed) == address) {
+        let _ = slot.block.compare_exchange(address, 0, Ordering::AcqRel, Ordering::Relaxed);
+    }
+}
+
+#[cfg(not(feature = "caller-tags"))]
+pub(crate) fn forget(_ptr: NonNull<u8>) {}
+
+/// Reporte sur `ptr` l'appelant enregistré avec `from`, lorsqu'un bloc est
+/// déplacé.
+///
+/// Rien ne change si l'un des deux blocs n'est pas enregistré.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn inherit(ptr: NonNull<u8>, from: NonNull<u8>) {
+    let slot_of = |ptr: NonNull<u8>| {
+        let address = ptr.as_ptr().addr();
+        SLOTS.iter().find(|slot| slot.block.load(Ordering::Acquire) == address)
+    };
+    if let (Some(slot), Some(from)) = (slot_of(ptr), slot_of(from)) {
+        slot.caller.store(from.caller.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+}
+
+#[cfg(not(feature = "caller-tags"))]
+pub(crate) fn inherit(_ptr: NonNull<u8>, _from: NonNull<u8>) {}
+
+/// Appelle `f` avec l'indice de l'emplacement, l'adresse et l'appelant de
+/// chaque bloc enregistré.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn for_each(mut f: impl FnMut(usize, usize, usize)) {
+    for (index, slot) in SLOTS.iter().enumerate() {
+        let block = slot.block.load(Ordering::Acquire);
+        if block > CLAIMED {
+            f(index, block, slot.caller.load(Ordering::Relaxed));
+        }
+    }
+}
+
+/// Retourne le nombre cumulé d'allocations non enregistrées faute
+/// d'emplacement, libérées ou non depuis.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn untracked() -> usize {
+    UNTRACKED.load(Ordering::Relaxed)
+}
+
+/// Écrit le rapport des fuites : une ligne par appelant et par catégorie.
+///
+/// Les blocs sont regroupés sans allocation ni copie de la table, en la
+/// parcourant une fois par groupe. `owner_of` donne la catégorie d'une
+/// adresse ; les blocs dont la catégorie est inconnue sont ignorés.
+#[cfg(feature = "caller-tags")]
+pub(crate) fn write_report(
+    writer: &mut impl fmt::Write,
+    owner_of: impl Fn(usize) -> Option<BlockSize>,
+) -> fmt::Result {
+    let mut result = Ok(());
+    for_each(|index, address, caller| {
+        let Some(block_size) = owner_of(address) else { return };
+        if result.is_err() {
+            return;
+        }
+        let (mut reported, mut count) = (false, 0);
+        for_each(|other_index, other, other_caller| {
+            if other_caller != caller || owner_of(other) != Some(block_size) {
+                return;
+            }
+            if other_index < index {
+                reported = true;
+            } else {
+                count += 1;
+            }
+        });
+        if !reported {
+            result = writeln!(
+                writer,
+                "caller {:#x}: {} live {}-byte blocks",
+                caller,
+                count,
+                block_size.size()
+            );
+        }
+    });
+    result?;
+    match untracked() {
+        0 => Ok(()),
+        untracked => writeln!(writer, "untracked: {} allocations", untracked),
+    }
+}
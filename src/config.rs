@@ -1,9 +1,13 @@
+use crate::arena;
+use crate::header;
+use crate::memory::SlabMemory;
+
 /// Représente les tailles de blocs supportées par l'allocateur mémoire.
 ///
 /// Chaque taille correspond à une catégorie utilisée pour organiser la mémoire
 /// dans des zones (slabs). Les valeurs associées (ex. `8`, `16`, ...) indiquent
 /// la taille réelle en octets des blocs de mémoire.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BlockSize {
     /// Bloc de 8 octets, adapté pour les allocations très petites.
     Tiny = 8,
@@ -31,6 +35,24 @@ impl BlockSize {
     /// [`BlockSize::Mammoth`].
     pub const MAX: usize = BlockSize::Mammoth as usize;
 
+    /// La taille minimale d'un bloc en octets, celle de [`BlockSize::Tiny`].
+    pub const MIN: usize = BlockSize::Tiny as usize;
+
+    /// Nombre de catégories, et donc d'arènes.
+    pub const COUNT: usize = 8;
+
+    /// Toutes les catégories, par taille croissante : `ALL[i].index() == i`.
+    pub const ALL: [BlockSize; BlockSize::COUNT] = [
+        BlockSize::Tiny,
+        BlockSize::Small,
+        BlockSize::Medium,
+        BlockSize::Large,
+        BlockSize::Huge,
+        BlockSize::Giant,
+        BlockSize::Colossal,
+        BlockSize::Mammoth,
+    ];
+
     /// Catégorise une taille en octets en fonction des tailles supportées.
     ///
     /// # Arguments
@@ -45,13 +67,13 @@ impl BlockSize {
     /// # Examples
     ///
     /// ```
-    /// use my_allocator::BlockSize;
+    /// use global_allocator::BlockSize;
     ///
     /// assert_eq!(BlockSize::categorize(10), Some(BlockSize::Small));
     /// assert_eq!(BlockSize::categorize(500), Some(BlockSize::Colossal));
     /// assert_eq!(BlockSize::categorize(1500), None); // Taille non supportée
     /// ```
-    pub fn categorize(size: usize) -> Option<Self> {
+    pub const fn categorize(size: usize) -> Option<Self> {
         match size {
             1..=8 => Some(BlockSize::Tiny),
             9..=16 => Some(BlockSize::Small),
@@ -64,4 +86,415 @@ impl BlockSize {
             _ => None,
         }
     }
+
+    /// Retourne l'index de l'arène associée à cette catégorie.
+    ///
+    /// Les arènes sont rangées par taille croissante : [`BlockSize::Tiny`]
+    /// correspond à l'index `0` et [`BlockSize::Mammoth`] à l'index `7`.
+    /// Les tableaux indexés par catégorie (par exemple
+    /// [`MemoryStats::classes`](crate::MemoryStats)) suivent le même ordre.
+    pub const fn index(self) -> usize {
+        (self as usize).trailing_zeros() as usize - 3
+    }
+
+    /// Retourne la catégorie d'index `index`, ou `None` si `index` dépasse
+    /// [`BlockSize::COUNT`].
+    ///
+    /// Réciproque de [`BlockSize::index`].
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index < Self::COUNT {
+            Some(Self::ALL[index])
+        } else {
+            None
+        }
+    }
+
+    /// Retourne la taille des blocs de cette catégorie, en octets.
+    pub const fn size(self) -> usize {
+        self as usize
+    }
+
+    /// Retourne un itérateur sur toutes les catégories, par taille croissante.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use global_allocator::BlockSize;
+    ///
+    /// let sizes: Vec<usize> = BlockSize::iter().map(BlockSize::size).collect();
+    /// assert_eq!(sizes, [8, 16, 32, 64, 128, 256, 512, 1024]);
+    /// assert!(BlockSize::iter().enumerate().all(|(i, b)| b.index() == i));
+    /// ```
+    pub fn iter() -> core::array::IntoIter<BlockSize, { BlockSize::COUNT }> {
+        Self::ALL.into_iter()
+    }
+}
+
+/// Erreur retournée par la conversion d'une taille qui n'est pas exactement
+/// celle d'une catégorie.
+///
+/// Contient la taille refusée.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InvalidBlockSize(pub usize);
+
+impl InvalidBlockSize {
+    /// Retourne le code stable et négatif de l'erreur, pour une interface
+    /// FFI : `-16` (voir la table des codes du readme).
+    ///
+    /// Le code ne transporte pas la taille refusée.
+    pub const fn into_raw(self) -> i16 {
+        -16
+    }
+
+    /// Retrouve l'erreur correspondant à un code, ou `None` s'il est inconnu.
+    ///
+    /// La taille refusée n'étant pas transportée par le code, l'erreur
+    /// retrouvée contient `0`.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::InvalidBlockSize;
+    ///
+    /// let code = InvalidBlockSize(60).into_raw();
+    /// assert_eq!(InvalidBlockSize::from_raw(code), Some(InvalidBlockSize(0)));
+    /// assert_eq!(InvalidBlockSize::try_from(-1), Err(-1));
+    /// ```
+    pub const fn from_raw(code: i16) -> Option<Self> {
+        match code {
+            -16 => Some(Self(0)),
+            _ => None,
+        }
+    }
+
+    /// Retourne une courte description de l'erreur, à la manière de `strerror`.
+    ///
+    /// Disponible uniquement avec la feature `error-strings`.
+    #[cfg(feature = "error-strings")]
+    pub const fn as_str(self) -> &'static str {
+        "not a block size"
+    }
+}
+
+impl From<InvalidBlockSize> for i16 {
+    fn from(error: InvalidBlockSize) -> i16 {
+        error.into_raw()
+    }
+}
+
+impl TryFrom<i16> for InvalidBlockSize {
+    type Error = i16;
+
+    /// Retrouve l'erreur d'un code, ou retourne le code s'il est inconnu.
+    fn try_from(code: i16) -> Result<Self, i16> {
+        Self::from_raw(code).ok_or(code)
+    }
+}
+
+impl TryFrom<usize> for BlockSize {
+    type Error = InvalidBlockSize;
+
+    /// Convertit une taille de bloc exacte en catégorie.
+    ///
+    /// Contrairement à [`BlockSize::categorize`], la taille n'est pas arrondie :
+    /// seules `8`, `16`, ..., `1024` sont acceptées.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use global_allocator::{BlockSize, InvalidBlockSize};
+    ///
+    /// assert_eq!(BlockSize::try_from(64), Ok(BlockSize::Large));
+    /// assert_eq!(BlockSize::try_from(60), Err(InvalidBlockSize(60)));
+    /// ```
+    fn try_from(size: usize) -> Result<Self, Self::Error> {
+        match BlockSize::categorize(size) {
+            Some(block_size) if block_size.size() == size => Ok(block_size),
+            _ => Err(InvalidBlockSize(size)),
+        }
+    }
+}
+
+impl From<BlockSize> for usize {
+    fn from(block_size: BlockSize) -> usize {
+        block_size.size()
+    }
+}
+
+/// Répartition du tas entre les arènes.
+///
+/// Chaque catégorie reçoit une part du tas proportionnelle à son poids dans
+/// `shares`, indexé par taille de bloc croissante. Une catégorie de poids nul
+/// n'a pas de blocs.
+///
+/// # Exemple
+///
+/// ```rust
+/// use global_allocator::{ArenaConfig, SlabMemory};
+///
+/// let heap: &'static mut [u8] = Box::leak(vec![0u8; 32 * 1024].into_boxed_slice());
+/// unsafe {
+///     SlabMemory::initialize_with(heap.as_mut_ptr(), heap.len(), ArenaConfig::preset_small());
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ArenaConfig {
+    /// Poids relatif de chaque catégorie, de 8 à 1024 octets.
+    pub shares: [u32; 8],
+}
+
+impl ArenaConfig {
+    /// Répartition égale du tas entre toutes les catégories.
+    pub const fn uniform() -> Self {
+        Self { shares: [1; 8] }
+    }
+
+    /// Répartition pour les petites cibles (16 à 64 Ko de RAM).
+    ///
+    /// Favorise les petits blocs (nœuds, chaînes courtes, `Box` de petites
+    /// structures) et garde peu de gros blocs.
+    pub const fn preset_small() -> Self {
+        Self {
+            shares: [12, 18, 20, 18, 14, 10, 5, 3],
+        }
+    }
+
+    /// Répartition pour les cibles moyennes (64 à 256 Ko de RAM).
+    ///
+    /// Plus équilibrée que [`ArenaConfig::preset_small`], avec davantage de
+    /// place pour les tampons de taille moyenne.
+    pub const fn preset_medium() -> Self {
+        Self {
+            shares: [8, 12, 15, 16, 15, 13, 11, 10],
+        }
+    }
+
+    /// Répartition pour les piles réseau.
+    ///
+    /// Réserve l'essentiel du tas aux catégories de 512 et 1024 octets pour
+    /// les tampons de trames, et garde des petits blocs pour les descripteurs.
+    /// Une trame Ethernet complète (1,5 Ko) dépasse [`BlockSize::MAX`] et doit
+    /// être répartie sur plusieurs blocs.
+    pub const fn preset_network() -> Self {
+        Self {
+            shares: [4, 6, 10, 10, 8, 7, 15, 40],
+        }
+    }
+
+    /// Retourne le nombre d'octets attribués à l'arène d'index `index` pour un
+    /// tas de `heap_size` octets, arrondi à un multiple de sa taille de bloc.
+    ///
+    /// Retourne `None` si le calcul déborde, ce qui peut arriver sur une cible
+    /// 32 bits avec de très grands poids.
+    pub(crate) const fn arena_bytes(&self, index: usize, heap_size: usize) -> Option<usize> {
+        let Some(total) = self.total_shares() else {
+            return None;
+        };
+        if total == 0 {
+            return Some(0);
+        }
+        let share = self.shares[index] as usize;
+        // `heap_size / total * share` et le reste ne dépassent pas `heap_size` :
+        // seuls les produits intermédiaires peuvent déborder.
+        let Some(whole) = (heap_size / total).checked_mul(share) else {
+            return None;
+        };
+        let Some(rest) = (heap_size % total).checked_mul(share) else {
+            return None;
+        };
+        let bytes = whole + rest / total;
+        let block_size = BlockSize::ALL[index].size();
+        Some(bytes - bytes % block_size)
+    }
+
+    /// Retourne la somme des poids, ou `None` si elle déborde.
+    const fn total_shares(&self) -> Option<usize> {
+        let mut total: usize = 0;
+        let mut i = 0;
+        while i < self.shares.len() {
+            total = match total.checked_add(self.shares[i] as usize) {
+                Some(total) => total,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(total)
+    }
+
+    /// Retourne la plus petite taille de tas qui, avec cette répartition,
+    /// sert simultanément toutes les allocations de `demand`.
+    ///
+    /// Chaque couple `(taille, nombre)` de `demand` représente `nombre`
+    /// allocations vivantes de `taille` octets. Le calcul compte l'en-tête
+    /// de chaque bloc (feature `block-header`), l'arrondi de chaque arène à sa
+    /// taille de bloc et l'alignement du début du tas sur
+    /// [`SlabMemory::MIN_ALIGN`], dans le pire cas.
+    ///
+    /// Évaluable à la compilation ; voir [`estimate_heap!`](crate::estimate_heap).
+    ///
+    /// # Returns
+    ///
+    /// La taille minimale du tas, en octets, ou `None` si une taille dépasse
+    /// [`BlockSize::MAX`], si une catégorie demandée a un poids nul ou
+    /// dépasse le nombre maximal de blocs d'une arène, ou si le calcul déborde.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::ArenaConfig;
+    ///
+    /// let config = ArenaConfig::uniform();
+    /// assert!(config.required_heap(&[(24, 16), (120, 4)]).is_some());
+    /// assert_eq!(config.required_heap(&[(2048, 1)]), None);
+    /// ```
+    pub const fn required_heap(&self, demand: &[(usize, usize)]) -> Option<usize> {
+        let mut needed = [0usize; BlockSize::COUNT];
+        let mut i = 0;
+        while i < demand.len() {
+            let (size, count) = demand[i];
+            let Some(block_size) = BlockSize::categorize(header::block_request(size)) else {
+                return None;
+            };
+            let Some(bytes) = count.checked_mul(block_size.size()) else {
+                return None;
+            };
+            let Some(total) = needed[block_size.index()].checked_add(bytes) else {
+                return None;
+            };
+            needed[block_size.index()] = total;
+            i += 1;
+        }
+
+        let Some(total) = self.total_shares() else {
+            return None;
+        };
+        let mut heap_size = 0;
+        let mut index = 0;
+        while index < BlockSize::COUNT {
+            let bytes = needed[index];
+            if bytes != 0 {
+                let share = self.shares[index] as usize;
+                if share == 0 || bytes / BlockSize::ALL[index].size() > arena::INDEX_MASK {
+                    return None;
+                }
+                let Some(scaled) = bytes.checked_mul(total) else {
+                    return None;
+                };
+                let arena_heap = scaled.div_ceil(share);
+                if arena_heap > heap_size {
+                    heap_size = arena_heap;
+                }
+            }
+            index += 1;
+        }
+        heap_size.checked_add(SlabMemory::MIN_ALIGN - 1)
+    }
+
+    /// Indique si un tas de `heap_size` octets sert simultanément toutes les
+    /// allocations de `demand` (voir [`ArenaConfig::required_heap`]).
+    pub const fn fits(&self, heap_size: usize, demand: &[(usize, usize)]) -> bool {
+        match self.required_heap(demand) {
+            Some(required) => required <= heap_size,
+            None => false,
+        }
+    }
+}
+
+/// Vérifie à la compilation qu'un tas de `budget` octets, réparti selon
+/// `classes`, sert simultanément toutes les allocations de `demand`.
+///
+/// Chaque couple `(taille, nombre)` représente `nombre` allocations vivantes
+/// de `taille` octets. Une configuration qui ne tient pas dans le budget,
+/// en-têtes de blocs et alignement compris, fait échouer la compilation au
+/// lieu d'échouer à l'exécution sur la cible. La taille minimale est donnée
+/// par [`ArenaConfig::required_heap`].
+///
+/// # Exemple
+///
+/// ```rust
+/// use global_allocator::{estimate_heap, ArenaConfig};
+///
+/// estimate_heap!(
+///     classes: ArenaConfig::preset_small(),
+///     demand: [(24, 16), (120, 4), (500, 2)],
+///     budget: 32 * 1024,
+/// );
+/// ```
+///
+/// Un budget insuffisant est refusé :
+///
+/// ```compile_fail
+/// use global_allocator::{estimate_heap, ArenaConfig};
+///
+/// estimate_heap!(
+///     classes: ArenaConfig::preset_small(),
+///     demand: [(512, 16)],
+///     budget: 32 * 1024,
+/// );
+/// ```
+#[macro_export]
+macro_rules! estimate_heap {
+    (
+        classes: $classes:expr,
+        demand: [$(($size:expr, $count:expr)),* $(,)?],
+        budget: $budget:expr $(,)?
+    ) => {
+        const _: () = assert!(
+            $crate::ArenaConfig::fits(&$classes, $budget, &[$(($size, $count)),*]),
+            "the heap budget cannot serve the declared demand"
+        );
+    };
+}
+
+impl Default for ArenaConfig {
+    /// Retourne [`ArenaConfig::uniform`].
+    fn default() -> Self {
+        Self::uniform()
+    }
+}
+
+/// Réponse de l'allocateur à une demande de taille nulle.
+///
+/// Installée via [`SlabMemory::set_zero_size_policy`](crate::SlabMemory::set_zero_size_policy).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ZeroSizePolicy {
+    /// Retourne un pointeur non nul aligné sur `layout.align()`, sans
+    /// consommer de bloc, comme `NonNull::dangling` pour les types de taille
+    /// nulle. Sa libération est ignorée.
+    #[default]
+    Dangling = 0,
+    /// Consomme le plus petit bloc : chaque allocation reçoit une adresse
+    /// distincte, comme `malloc(0)` dans certaines bibliothèques C.
+    Block = 1,
+    /// Échoue, comme toute demande qu'aucune catégorie ne peut servir.
+    Null = 2,
+}
+
+impl ZeroSizePolicy {
+    /// Retrouve une politique à partir de sa valeur `repr(u8)`.
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => ZeroSizePolicy::Block,
+            2 => ZeroSizePolicy::Null,
+            _ => ZeroSizePolicy::Dangling,
+        }
+    }
+}
+
+/// Ordre dans lequel une arène réutilise ses blocs libérés.
+///
+/// Installé par catégorie via
+/// [`SlabMemory::set_reuse_policy`](crate::SlabMemory::set_reuse_policy).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ReusePolicy {
+    /// Le dernier bloc libéré est le premier réalloué : il est encore dans
+    /// le cache du processeur.
+    #[default]
+    Lifo,
+    /// Le premier bloc libéré est le premier réalloué : un bloc libéré reste
+    /// libre le plus longtemps possible, ce qui laisse le temps de détecter
+    /// une utilisation après libération (vérification de la liste des blocs
+    /// libres, feature `paranoid`).
+    Fifo,
 }
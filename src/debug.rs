@@ -0,0 +1,39 @@
+use core::fmt::Write;
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Fonction recevant les octets de la sortie de débogage, ou `null` si aucune
+/// n'est installée.
+static SINK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Installe ou retire la fonction recevant la sortie de débogage.
+pub(crate) fn install(sink: Option<fn(u8)>) {
+    let sink = sink.map_or(ptr::null_mut(), |sink| sink as *mut ());
+    SINK.store(sink, Ordering::Release);
+}
+
+/// Structure pour écrire des messages de débogage.
+///
+/// Implémente le trait [`core::fmt::Write`] pour permettre des sorties
+/// formatées via `write!` ou `writeln!`. Les octets sont remis à la fonction
+/// installée par [`SlabMemory::set_debug_sink`](crate::SlabMemory::set_debug_sink) ;
+/// sans elle, ils sont ignorés.
+pub(crate) struct DebugWriter;
+
+impl Write for DebugWriter {
+    /// Écrit une chaîne de caractères, octet par octet, dans la sortie
+    /// installée.
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let sink = SINK.load(Ordering::Acquire);
+        if sink.is_null() {
+            return Ok(());
+        }
+        // Seules des fonctions `fn(u8)` sont stockées dans `SINK`.
+        let sink = unsafe { mem::transmute::<*mut (), fn(u8)>(sink) };
+        for byte in s.bytes() {
+            sink(byte);
+        }
+        Ok(())
+    }
+}
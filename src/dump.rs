@@ -0,0 +1,196 @@
+#[cfg(feature = "debug")]
+use crate::arena::Arena;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Signature placée au début de chaque image du tas.
+pub const DUMP_MAGIC: [u8; 4] = *b"SLAB";
+
+/// Version du format binaire produit par [`SlabMemory::dump`](crate::SlabMemory::dump).
+pub const DUMP_VERSION: u8 = 1;
+
+/// Taille de l'en-tête global, en octets.
+#[cfg(feature = "std")]
+const HEADER_LEN: usize = 6;
+
+/// Taille de l'en-tête de chaque arène, en octets.
+#[cfg(feature = "std")]
+const ARENA_HEADER_LEN: usize = 16;
+
+/// Nombre d'octets de bitmap calculés à la fois lors de l'écriture.
+#[cfg(feature = "debug")]
+const CHUNK_BYTES: usize = 64;
+
+/// Destination des octets produits par [`SlabMemory::dump`](crate::SlabMemory::dump).
+///
+/// Implémenté pour toute fermeture `FnMut(&[u8])`, ce qui permet d'envoyer
+/// l'image directement vers un port série, un canal RTT ou un buffer.
+#[cfg(feature = "debug")]
+pub trait DumpSink {
+    /// Écrit une suite d'octets dans la destination.
+    fn write_bytes(&mut self, bytes: &[u8]);
+}
+
+#[cfg(feature = "debug")]
+impl<F: FnMut(&[u8])> DumpSink for F {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self(bytes)
+    }
+}
+
+/// Écrit l'en-tête global de l'image.
+///
+/// # Arguments
+///
+/// - `sink`: Destination des octets.
+/// - `arena_count`: Nombre d'enregistrements d'arène qui suivent.
+#[cfg(feature = "debug")]
+pub(crate) fn write_header<S: DumpSink>(sink: &mut S, arena_count: u8) {
+    sink.write_bytes(&DUMP_MAGIC);
+    sink.write_bytes(&[DUMP_VERSION, arena_count]);
+}
+
+/// Écrit l'enregistrement d'une arène : en-tête puis bitmap d'allocation.
+///
+/// La bitmap est calculée par tranches de [`CHUNK_BYTES`] octets afin de ne
+/// jamais allouer : pour chaque tranche, la liste des blocs libres est
+/// parcourue une fois.
+///
+/// # Arguments
+///
+/// - `sink`: Destination des octets.
+/// - `block_size`: Taille des blocs de la catégorie.
+/// - `arena`: Arène de la catégorie, ou `None` si elle n'est pas initialisée.
+#[cfg(feature = "debug")]
+pub(crate) fn write_arena<S: DumpSink>(sink: &mut S, block_size: usize, arena: Option<&Arena>) {
+    let (start, count) = match arena {
+        Some(arena) => (arena.start().as_ptr().addr(), arena.block_count()),
+        None => (0, 0),
+    };
+    sink.write_bytes(&(block_size as u32).to_le_bytes());
+    sink.write_bytes(&(count as u32).to_le_bytes());
+    sink.write_bytes(&(start as u64).to_le_bytes());
+
+    let Some(arena) = arena else {
+        return;
+    };
+    let mut first = 0;
+    while first < count {
+        let blocks = (count - first).min(CHUNK_BYTES * 8);
+        let mut chunk = [0xFFu8; CHUNK_BYTES];
+        for block in arena.free_blocks() {
+            let index = (block.addr() - start) / arena.block_size();
+            if index >= first && index < first + blocks {
+                let bit = index - first;
+                chunk[bit / 8] &= !(1 << (bit % 8));
+            }
+        }
+        let len = blocks.div_ceil(8);
+        if blocks % 8 != 0 {
+            chunk[len - 1] &= (1 << (blocks % 8)) - 1;
+        }
+        sink.write_bytes(&chunk[..len]);
+        first += blocks;
+    }
+}
+
+/// Erreurs possibles lors du décodage d'une image du tas.
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum DumpError {
+    /// L'image ne commence pas par [`DUMP_MAGIC`].
+    BadMagic,
+    /// La version de l'image n'est pas supportée par ce décodeur.
+    UnsupportedVersion(u8),
+    /// L'image se termine avant la fin d'un enregistrement.
+    Truncated,
+}
+
+/// Image décodée de l'état du tas.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct HeapDump {
+    /// Version du format de l'image.
+    pub version: u8,
+    /// Enregistrements des arènes, par taille de bloc croissante.
+    pub arenas: Vec<ArenaDump>,
+}
+
+/// État décodé d'une arène.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ArenaDump {
+    /// Taille de chaque bloc, en octets.
+    pub block_size: u32,
+    /// Nombre de blocs de l'arène (`0` si elle n'était pas initialisée).
+    pub block_count: u32,
+    /// Adresse de début de l'arène sur la cible.
+    pub start: u64,
+    /// Bitmap d'allocation : bit `i` (poids faible d'abord) à `1` si le bloc `i` est alloué.
+    pub bitmap: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl ArenaDump {
+    /// Indique si le bloc d'index `block` était alloué au moment de l'image.
+    pub fn is_allocated(&self, block: usize) -> bool {
+        self.bitmap
+            .get(block / 8)
+            .is_some_and(|byte| byte & (1 << (block % 8)) != 0)
+    }
+
+    /// Retourne le nombre de blocs alloués dans l'arène.
+    pub fn allocated_count(&self) -> usize {
+        self.bitmap.iter().map(|byte| byte.count_ones() as usize).sum()
+    }
+}
+
+/// Décode une image produite par [`SlabMemory::dump`](crate::SlabMemory::dump).
+///
+/// Disponible uniquement avec la feature `std`, pour les outils côté hôte.
+///
+/// # Arguments
+///
+/// - `bytes`: L'image complète reçue depuis la cible.
+///
+/// # Returns
+///
+/// L'état décodé du tas, ou une [`DumpError`] si l'image est invalide.
+#[cfg(feature = "std")]
+pub fn decode(bytes: &[u8]) -> Result<HeapDump, DumpError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(DumpError::Truncated);
+    }
+    if bytes[..4] != DUMP_MAGIC {
+        return Err(DumpError::BadMagic);
+    }
+    let version = bytes[4];
+    if version != DUMP_VERSION {
+        return Err(DumpError::UnsupportedVersion(version));
+    }
+
+    let mut arenas = Vec::with_capacity(bytes[5] as usize);
+    let mut rest = &bytes[HEADER_LEN..];
+    for _ in 0..bytes[5] {
+        if rest.len() < ARENA_HEADER_LEN {
+            return Err(DumpError::Truncated);
+        }
+        let block_size = u32::from_le_bytes(rest[0..4].try_into().unwrap());
+        let block_count = u32::from_le_bytes(rest[4..8].try_into().unwrap());
+        let start = u64::from_le_bytes(rest[8..16].try_into().unwrap());
+        let len = (block_count as usize).div_ceil(8);
+        rest = &rest[ARENA_HEADER_LEN..];
+        if rest.len() < len {
+            return Err(DumpError::Truncated);
+        }
+        arenas.push(ArenaDump {
+            block_size,
+            block_count,
+            start,
+            bitmap: rest[..len].to_vec(),
+        });
+        rest = &rest[len..];
+    }
+
+    Ok(HeapDump { version, arenas })
+}
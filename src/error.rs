@@ -0,0 +1,92 @@
+/// Erreur retournée par
+/// [`SlabMemory::try_allocate`](crate::SlabMemory::try_allocate) : raison
+/// pour laquelle aucun bloc n'a été remis.
+///
+/// Chaque variante a un code stable et négatif, à la manière des codes
+/// d'erreur C, pour traverser une interface FFI (voir
+/// [`AllocationError::into_raw`] et la table des codes du readme).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(i16)]
+pub enum AllocationError {
+    /// Demande de taille nulle sous
+    /// [`ZeroSizePolicy::Null`](crate::ZeroSizePolicy::Null).
+    ZeroSize = -1,
+    /// La taille demandée dépasse celle du plus grand bloc.
+    TooLarge = -2,
+    /// L'allocateur est empoisonné par
+    /// [`FatalPolicy::Poison`](crate::FatalPolicy::Poison).
+    Poisoned = -3,
+    /// L'alignement demandé dépasse celui que garantissent les pointeurs de
+    /// la catégorie.
+    Misaligned = -4,
+    /// Échec injecté par `SlabMemory::fail_next` (feature `fault-injection`).
+    Injected = -5,
+    /// La demande sort du plan d'allocation installé (feature
+    /// `allocation-map`).
+    OutsideAllocationMap = -6,
+    /// L'arène de la catégorie, et sa catégorie de repli si elle est activée,
+    /// n'ont plus de bloc libre.
+    OutOfMemory = -7,
+}
+
+impl AllocationError {
+    /// Retourne le code stable de l'erreur.
+    pub const fn into_raw(self) -> i16 {
+        self as i16
+    }
+
+    /// Retrouve l'erreur correspondant à un code, ou `None` s'il est inconnu.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::AllocationError;
+    ///
+    /// let code = i16::from(AllocationError::OutOfMemory);
+    /// assert_eq!(AllocationError::try_from(code), Ok(AllocationError::OutOfMemory));
+    /// assert_eq!(AllocationError::from_raw(0), None);
+    /// ```
+    pub const fn from_raw(code: i16) -> Option<Self> {
+        match code {
+            -1 => Some(Self::ZeroSize),
+            -2 => Some(Self::TooLarge),
+            -3 => Some(Self::Poisoned),
+            -4 => Some(Self::Misaligned),
+            -5 => Some(Self::Injected),
+            -6 => Some(Self::OutsideAllocationMap),
+            -7 => Some(Self::OutOfMemory),
+            _ => None,
+        }
+    }
+
+    /// Retourne une courte description de l'erreur, à la manière de `strerror`.
+    ///
+    /// Disponible uniquement avec la feature `error-strings`.
+    #[cfg(feature = "error-strings")]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ZeroSize => "zero-size allocation refused",
+            Self::TooLarge => "larger than the largest block",
+            Self::Poisoned => "allocator poisoned",
+            Self::Misaligned => "alignment not guaranteed by the class",
+            Self::Injected => "injected failure",
+            Self::OutsideAllocationMap => "outside the allocation map",
+            Self::OutOfMemory => "out of memory",
+        }
+    }
+}
+
+impl From<AllocationError> for i16 {
+    fn from(error: AllocationError) -> i16 {
+        error.into_raw()
+    }
+}
+
+impl TryFrom<i16> for AllocationError {
+    type Error = i16;
+
+    /// Retrouve l'erreur d'un code, ou retourne le code s'il est inconnu.
+    fn try_from(code: i16) -> Result<Self, i16> {
+        Self::from_raw(code).ok_or(code)
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "debug")]
+use core::fmt::Write;
+use core::mem;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicU8, Ordering};
+#[cfg(feature = "debug")]
+use crate::debug::DebugWriter;
+
+/// Réaction de l'allocateur à une corruption de ses structures internes.
+///
+/// Une corruption (un en-tête de bloc invalide avec la feature
+/// `block-header`, ou la libération d'un pointeur qui ne désigne aucun bloc)
+/// signifie que la mémoire du tas a été écrasée ou qu'un appelant la gère
+/// mal : rien de ce qu'elle contient n'est plus fiable. Installée via
+/// [`SlabMemory::set_fatal_policy`](crate::SlabMemory::set_fatal_policy).
+///
+/// Sans politique installée, le bloc en cause est seulement écarté et compté.
+#[derive(Copy, Clone, Debug)]
+pub enum FatalPolicy {
+    /// Signale la corruption sur la sortie de débogage puis boucle
+    /// indéfiniment, pour qu'un débogueur puisse examiner l'état du tas.
+    Halt,
+    /// Signale la corruption puis appelle la fonction de réinitialisation
+    /// fournie. Avec la feature `panic-handler`, elle sert aussi après une
+    /// panique.
+    Reset(fn() -> !),
+    /// Signale la corruption puis empoisonne l'allocateur : toute allocation
+    /// ultérieure échoue et toute libération est ignorée.
+    Poison,
+}
+
+/// Politique active : `0` aucune, `1` [`FatalPolicy::Halt`], `2`
+/// [`FatalPolicy::Reset`], `3` [`FatalPolicy::Poison`].
+static POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// Fonction de réinitialisation de [`FatalPolicy::Reset`].
+///
+/// Écrite avant [`POLICY`] : un contexte qui lit la politique `2` voit aussi
+/// la fonction.
+static RESET: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Indique que l'allocateur a été empoisonné par [`FatalPolicy::Poison`].
+static POISONED: AtomicBool = AtomicBool::new(false);
+
+/// Installe la politique appliquée aux corruptions.
+pub(crate) fn install(policy: FatalPolicy) {
+    let code = match policy {
+        FatalPolicy::Halt => 1,
+        FatalPolicy::Reset(reset) => {
+            RESET.store(reset as *mut (), Ordering::Relaxed);
+            2
+        }
+        FatalPolicy::Poison => 3,
+    };
+    POLICY.store(code, Ordering::Release);
+}
+
+/// Retourne la fonction de réinitialisation si [`FatalPolicy::Reset`] est la
+/// politique active.
+#[cfg(feature = "panic-handler")]
+pub(crate) fn reset_hook() -> Option<fn() -> !> {
+    if POLICY.load(Ordering::Acquire) != 2 {
+        return None;
+    }
+    // Seules des fonctions `fn() -> !` sont stockées dans `RESET`.
+    Some(unsafe { mem::transmute::<*mut (), fn() -> !>(RESET.load(Ordering::Relaxed)) })
+}
+
+/// Indique si l'allocateur a été empoisonné.
+pub(crate) fn is_poisoned() -> bool {
+    POISONED.load(Ordering::Relaxed)
+}
+
+/// Signale une corruption détectée sur `block` et applique la politique
+/// active.
+///
+/// Ne retourne que sans politique ou avec [`FatalPolicy::Poison`].
+#[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+pub(crate) fn corruption(what: &str, block: NonNull<u8>) {
+    #[cfg(feature = "debug")]
+    let _ = writeln!(DebugWriter, "{} at {:p}", what, block);
+    match POLICY.load(Ordering::Acquire) {
+        1 => loop {
+            core::hint::spin_loop();
+        },
+        2 => {
+            // Seules des fonctions `fn() -> !` sont stockées dans `RESET`.
+            let reset = unsafe { mem::transmute::<*mut (), fn() -> !>(RESET.load(Ordering::Relaxed)) };
+            reset()
+        }
+        3 => POISONED.store(true, Ordering::Relaxed),
+        _ => {}
+    }
+}
@@ -0,0 +1,169 @@
+use core::alloc::Layout;
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr;
+use crate::memory::SlabMemory;
+use crate::utils::align_to;
+
+/// Alignement garanti par [`malloc`], comme `max_align_t` en C.
+const MALLOC_ALIGN: usize = 2 * size_of::<usize>();
+
+/// Taille du préfixe placé juste devant chaque pointeur retourné.
+///
+/// Il contient l'adresse du bloc de l'allocateur et la taille de ce bloc,
+/// qui permettent à [`free`] de retrouver le layout de l'allocation.
+const PREFIX_SIZE: usize = 2 * size_of::<usize>();
+
+/// Alloue `size` octets alignés sur `align`, précédés de leur préfixe.
+///
+/// Les blocs de l'allocateur sont alignés au moins sur
+/// [`SlabMemory::MIN_ALIGN`] : le bloc est agrandi de ce qui manque pour
+/// atteindre `align` et le pointeur retourné est décalé en conséquence.
+///
+/// # Safety
+///
+/// `align` doit être une puissance de deux.
+unsafe fn allocate_prefixed(size: usize, align: usize) -> *mut c_void {
+    let align = align.max(MALLOC_ALIGN);
+    let Some(layout) = size
+        .checked_add(PREFIX_SIZE)
+        .and_then(|size| size.checked_add(align.saturating_sub(SlabMemory::MIN_ALIGN)))
+        .and_then(|total| Layout::from_size_align(total, SlabMemory::MIN_ALIGN).ok())
+    else {
+        return ptr::null_mut();
+    };
+    let block = unsafe { SlabMemory::allocate(layout) };
+    if block.is_null() {
+        return ptr::null_mut();
+    }
+    let offset = align_to(block.addr() + PREFIX_SIZE, align) - block.addr();
+    let user = unsafe { block.add(offset) };
+    let prefix = unsafe { user.cast::<usize>().sub(2) };
+    unsafe {
+        prefix.cast::<*mut u8>().write(block);
+        prefix.add(1).write(layout.size());
+    }
+    user.cast()
+}
+
+/// Retrouve le bloc et le layout d'un pointeur retourné par [`allocate_prefixed`].
+///
+/// # Safety
+///
+/// `ptr` doit avoir été retourné par l'une des fonctions de ce module et ne
+/// pas avoir été libéré.
+unsafe fn block_of(ptr: *mut c_void) -> (*mut u8, Layout) {
+    let prefix = unsafe { ptr.cast::<usize>().sub(2) };
+    let (block, total) = unsafe { (prefix.cast::<*mut u8>().read(), prefix.add(1).read()) };
+    let layout = unsafe { Layout::from_size_align_unchecked(total, SlabMemory::MIN_ALIGN) };
+    (block, layout)
+}
+
+/// Alloue `size` octets, comme le `malloc` de la bibliothèque C.
+///
+/// Le bloc est aligné sur deux mots. Retourne `NULL` si aucun bloc ne
+/// convient ; une taille nulle retourne un bloc unique valide pour [`free`].
+///
+/// # Safety
+///
+/// Le bloc doit être libéré par [`free`] ou redimensionné par [`realloc`].
+#[cfg_attr(feature = "ffi-export", no_mangle)]
+pub unsafe extern "C" fn malloc(size: usize) -> *mut c_void {
+    unsafe { allocate_prefixed(size, MALLOC_ALIGN) }
+}
+
+/// Alloue `count * size` octets mis à zéro, comme `calloc`.
+///
+/// Retourne `NULL` si la multiplication déborde.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`malloc`].
+#[cfg_attr(feature = "ffi-export", no_mangle)]
+pub unsafe extern "C" fn calloc(count: usize, size: usize) -> *mut c_void {
+    let Some(size) = count.checked_mul(size) else {
+        return ptr::null_mut();
+    };
+    let ptr = unsafe { allocate_prefixed(size, MALLOC_ALIGN) };
+    if !ptr.is_null() {
+        unsafe { ptr.cast::<u8>().write_bytes(0, size) };
+    }
+    ptr
+}
+
+/// Alloue `size` octets alignés sur `align`, comme `memalign`.
+///
+/// Retourne `NULL` si `align` n'est pas une puissance de deux. Le bloc est
+/// agrandi de `align` octets au plus pour pouvoir décaler le pointeur
+/// retourné.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`malloc`].
+#[cfg_attr(feature = "ffi-export", no_mangle)]
+pub unsafe extern "C" fn memalign(align: usize, size: usize) -> *mut c_void {
+    if !align.is_power_of_two() {
+        return ptr::null_mut();
+    }
+    unsafe { allocate_prefixed(size, align) }
+}
+
+/// Libère un bloc, comme `free`. Un pointeur nul est ignoré ; un pointeur
+/// qui ne vient pas de ce module est traité comme par
+/// [`SlabMemory::deallocate`], sans lire son préfixe.
+///
+/// # Safety
+///
+/// `ptr` doit être nul ou avoir été retourné par [`malloc`], [`calloc`],
+/// [`memalign`] ou [`realloc`], et ne pas avoir déjà été libéré.
+#[cfg_attr(feature = "ffi-export", no_mangle)]
+pub unsafe extern "C" fn free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    if SlabMemory::arena_of(ptr.cast()).is_none() {
+        // Sans préfixe à lire : la libération est seulement comptée et signalée.
+        unsafe { SlabMemory::deallocate(ptr.cast(), Layout::new::<u8>()) };
+        return;
+    }
+    let (block, layout) = unsafe { block_of(ptr) };
+    unsafe { SlabMemory::deallocate(block, layout) };
+}
+
+/// Redimensionne un bloc, comme `realloc`.
+///
+/// Le contenu est copié dans un nouveau bloc aligné comme par [`malloc`],
+/// jusqu'à la plus petite des deux tailles, puis l'ancien bloc est libéré.
+/// Un `ptr` nul équivaut à [`malloc`] ; une taille nulle libère le bloc et
+/// retourne `NULL`. En cas d'échec, `NULL` est retourné et l'ancien bloc
+/// reste valide. Un pointeur qui ne vient pas de ce module est traité comme
+/// par [`free`] : la libération étrangère est comptée et déclenche la
+/// [`FatalPolicy`](crate::FatalPolicy) installée, puis `NULL` est retourné.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`free`] pour `ptr`.
+#[cfg_attr(feature = "ffi-export", no_mangle)]
+pub unsafe extern "C" fn realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return unsafe { malloc(size) };
+    }
+    if size == 0 {
+        unsafe { free(ptr) };
+        return ptr::null_mut();
+    }
+    if SlabMemory::arena_of(ptr.cast()).is_none() {
+        unsafe { free(ptr) };
+        return ptr::null_mut();
+    }
+    let (block, layout) = unsafe { block_of(ptr) };
+    let old_size = layout.size() - (ptr.addr() - block.addr());
+    let new = unsafe { malloc(size) };
+    if !new.is_null() {
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.cast::<u8>(), new.cast::<u8>(), old_size.min(size));
+            free(ptr);
+        }
+    }
+    new
+}
@@ -0,0 +1,108 @@
+use core::ptr::NonNull;
+use crate::config::BlockSize;
+
+/// Taille de l'en-tête placé au début de chaque bloc, en octets.
+///
+/// Vaut `0` sans la feature `block-header`. Avec elle, l'en-tête occupe 8
+/// octets afin de préserver l'alignement des blocs.
+#[cfg(feature = "block-header")]
+pub const HEADER_SIZE: usize = 8;
+#[cfg(not(feature = "block-header"))]
+pub const HEADER_SIZE: usize = 0;
+
+/// Graine mélangée à la somme de contrôle des en-têtes.
+#[cfg(feature = "block-header")]
+const HEADER_SEED: u32 = 0x5AB1_C0DE;
+
+/// En-tête écrit au début d'un bloc alloué.
+#[cfg(feature = "block-header")]
+#[repr(C)]
+struct BlockHeader {
+    /// Taille de bloc de la catégorie d'origine, en octets.
+    class: u32,
+    /// Somme de contrôle de l'en-tête et de l'adresse du bloc.
+    check: u32,
+}
+
+/// Résultat de la vérification de l'en-tête d'un bloc libéré.
+pub(crate) enum Detached {
+    /// L'en-tête est intact et correspond au layout fourni.
+    Valid(NonNull<u8>, BlockSize),
+    /// L'en-tête est intact mais désigne une autre catégorie que le layout.
+    #[cfg(feature = "block-header")]
+    Mismatch(NonNull<u8>, BlockSize),
+    /// L'en-tête a été écrasé : le bloc ne doit pas être remis en liste.
+    #[cfg(feature = "block-header")]
+    Corrupt(NonNull<u8>),
+}
+
+/// Calcule la somme de contrôle de l'en-tête d'un bloc.
+#[cfg(feature = "block-header")]
+fn checksum(block: NonNull<u8>, class: u32) -> u32 {
+    (block.as_ptr().addr() as u32).rotate_left(7) ^ class.wrapping_mul(0x9E37_79B9) ^ HEADER_SEED
+}
+
+/// Retourne la taille de bloc nécessaire pour une demande de `size` octets.
+///
+/// Une demande de taille nulle servie par un bloc
+/// ([`ZeroSizePolicy::Block`](crate::ZeroSizePolicy::Block)) occupe au moins
+/// un octet.
+pub(crate) const fn block_request(size: usize) -> usize {
+    match size.saturating_add(HEADER_SIZE) {
+        0 => 1,
+        size => size,
+    }
+}
+
+/// Écrit l'en-tête d'un bloc et retourne le pointeur à remettre à l'appelant.
+///
+/// Sans la feature `block-header`, retourne `block` inchangé.
+///
+/// # Safety
+///
+/// `block` doit être un bloc de la catégorie `class` qui vient d'être alloué.
+#[cfg_attr(not(feature = "block-header"), allow(unused_variables))]
+pub(crate) unsafe fn attach(block: NonNull<u8>, class: BlockSize) -> NonNull<u8> {
+    #[cfg(feature = "block-header")]
+    {
+        let class = class as u32;
+        let header = BlockHeader {
+            class,
+            check: checksum(block, class),
+        };
+        unsafe {
+            block.cast::<BlockHeader>().write(header);
+            block.byte_add(HEADER_SIZE)
+        }
+    }
+    #[cfg(not(feature = "block-header"))]
+    block
+}
+
+/// Retrouve le bloc d'un pointeur utilisateur et vérifie son en-tête.
+///
+/// Sans la feature `block-header`, le bloc est considéré valide.
+///
+/// # Safety
+///
+/// `ptr` doit avoir été retourné par [`attach`].
+pub(crate) unsafe fn detach(ptr: NonNull<u8>, expected: BlockSize) -> Detached {
+    #[cfg(feature = "block-header")]
+    {
+        let block = unsafe { ptr.byte_sub(HEADER_SIZE) };
+        let header = unsafe { block.cast::<BlockHeader>().read() };
+        let class = match BlockSize::categorize(header.class as usize) {
+            Some(class) if class as u32 == header.class => class,
+            _ => return Detached::Corrupt(block),
+        };
+        if header.check != checksum(block, header.class) {
+            return Detached::Corrupt(block);
+        }
+        if class != expected {
+            return Detached::Mismatch(block, class);
+        }
+        Detached::Valid(block, class)
+    }
+    #[cfg(not(feature = "block-header"))]
+    Detached::Valid(ptr, expected)
+}
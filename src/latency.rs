@@ -0,0 +1,119 @@
+#[cfg(feature = "latency-budget")]
+use core::mem;
+#[cfg(feature = "latency-budget")]
+use core::ptr;
+#[cfg(feature = "latency-budget")]
+use core::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
+use crate::config::BlockSize;
+
+/// Budget de durée des allocations.
+///
+/// Chaque allocation est mesurée avec `clock` ; lorsqu'elle dure plus de
+/// `budget` unités, `on_overrun` est appelée avec la catégorie demandée et la
+/// durée mesurée. Installé via
+/// [`SlabMemory::set_latency_budget`](crate::SlabMemory::set_latency_budget).
+///
+/// Disponible uniquement avec la feature `latency-budget`.
+#[cfg(feature = "latency-budget")]
+#[derive(Copy, Clone, Debug)]
+pub struct LatencyBudget {
+    /// Compteur monotone fourni par l'utilisateur, par exemple `DWT::CYCCNT`.
+    /// Son débordement est géré.
+    pub clock: fn() -> u32,
+    /// Durée maximale d'une allocation, dans l'unité de `clock`.
+    pub budget: u32,
+    /// Appelée après chaque allocation trop longue. Elle s'exécute dans le
+    /// contexte de l'allocation et ne doit pas allouer.
+    pub on_overrun: fn(BlockSize, u32),
+}
+
+/// Compteur du budget actif.
+#[cfg(feature = "latency-budget")]
+static CLOCK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Durée maximale du budget actif.
+#[cfg(feature = "latency-budget")]
+static BUDGET: AtomicU32 = AtomicU32::new(0);
+
+/// Fonction de rappel du budget actif, ou `null` si aucun budget n'est installé.
+///
+/// Écrite en dernier lors de l'installation : un contexte qui la lit non nulle
+/// voit aussi [`CLOCK`] et [`BUDGET`].
+#[cfg(feature = "latency-budget")]
+static ON_OVERRUN: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Installe ou retire le budget de durée des allocations.
+#[cfg(feature = "latency-budget")]
+pub(crate) fn install(budget: Option<LatencyBudget>) {
+    ON_OVERRUN.store(ptr::null_mut(), Ordering::Release);
+    if let Some(budget) = budget {
+        CLOCK.store(budget.clock as *mut (), Ordering::Relaxed);
+        BUDGET.store(budget.budget, Ordering::Relaxed);
+        ON_OVERRUN.store(budget.on_overrun as *mut (), Ordering::Release);
+    }
+}
+
+/// Lit le compteur du budget actif, ou retourne `None` si aucun budget n'est
+/// installé.
+#[cfg(feature = "latency-budget")]
+fn now() -> Option<u32> {
+    if ON_OVERRUN.load(Ordering::Acquire).is_null() {
+        return None;
+    }
+    // Seules des fonctions `fn() -> u32` sont stockées dans `CLOCK`.
+    let clock = unsafe { mem::transmute::<*mut (), fn() -> u32>(CLOCK.load(Ordering::Relaxed)) };
+    Some(clock())
+}
+
+/// Mesure la durée d'une allocation jusqu'à sa destruction.
+#[cfg(feature = "latency-budget")]
+pub(crate) struct Stopwatch {
+    /// Catégorie de l'allocation mesurée.
+    block_size: BlockSize,
+    /// Valeur du compteur au début de l'allocation, si un budget est installé.
+    start: Option<u32>,
+}
+
+#[cfg(feature = "latency-budget")]
+impl Stopwatch {
+    /// Commence la mesure d'une allocation dans la catégorie `block_size`.
+    pub(crate) fn start(block_size: BlockSize) -> Self {
+        Self {
+            block_size,
+            start: now(),
+        }
+    }
+}
+
+#[cfg(feature = "latency-budget")]
+impl Drop for Stopwatch {
+    /// Termine la mesure et signale un dépassement du budget.
+    fn drop(&mut self) {
+        let (Some(start), Some(end)) = (self.start, now()) else {
+            return;
+        };
+        let elapsed = end.wrapping_sub(start);
+        if elapsed <= BUDGET.load(Ordering::Relaxed) {
+            return;
+        }
+        let on_overrun = ON_OVERRUN.load(Ordering::Acquire);
+        if !on_overrun.is_null() {
+            // Seules des fonctions `fn(BlockSize, u32)` sont stockées dans `ON_OVERRUN`.
+            let on_overrun = unsafe { mem::transmute::<*mut (), fn(BlockSize, u32)>(on_overrun) };
+            on_overrun(self.block_size, elapsed);
+        }
+    }
+}
+
+/// Sans la feature `latency-budget`, aucun budget ne peut être installé : la
+/// mesure est un type vide qui ne lit aucun compteur.
+#[cfg(not(feature = "latency-budget"))]
+pub(crate) struct Stopwatch;
+
+#[cfg(not(feature = "latency-budget"))]
+impl Stopwatch {
+    /// Ne mesure rien.
+    pub(crate) fn start(_block_size: BlockSize) -> Self {
+        Stopwatch
+    }
+}
@@ -1,43 +1,134 @@
-#![no_std] 
-#![feature(allocator_api)]
+#![no_std]
+#![deny(unsafe_op_in_unsafe_fn)]
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Module d'audit des alignements demandés.
+mod alignment;
+/// Module du plan d'allocation déclaré à la compilation.
+mod allocation_map;
 /// Module contenant l'implémentation des arènes mémoire.
 mod arena;
+/// Module de la charge de test mesurant la durée des opérations.
+#[cfg(feature = "bench")]
+pub mod bench;
+/// Module d'enregistrement de l'appelant des blocs vivants.
+mod callers;
 /// Module contenant les définitions des tailles de blocs et leur catégorisation.
 mod config;
+/// Module de la sortie de débogage.
+#[cfg(feature = "debug")]
+mod debug;
+/// Module du format binaire d'image du tas et de son décodeur.
+#[cfg(any(feature = "debug", feature = "std"))]
+pub mod dump;
+/// Module de l'erreur retournée par une allocation refusée.
+mod error;
+/// Module de la politique appliquée aux corruptions internes.
+mod fatal;
+/// Module des fonctions `malloc`/`free` pour le code C.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Module de l'en-tête optionnel de vérification des blocs.
+mod header;
+/// Module de mesure de la durée des allocations.
+mod latency;
+/// Module contenant le verrou tournant qui protège chaque arène.
+mod lock;
+/// Module des magasins de blocs propres à chaque contexte.
+#[cfg(feature = "magazines")]
+mod magazine;
 /// Module principal gérant l'allocateur mémoire.
 mod memory;
+/// Module d'export des statistiques au format texte de Prometheus.
+#[cfg(feature = "metrics")]
+mod metrics;
+/// Module du gestionnaire de panique affichant l'état du tas.
+#[cfg(feature = "panic-handler")]
+mod panic;
+/// Module du pool de blocs de taille fixe utilisable seul.
+mod pool;
+/// Module des types d'usage courant, à importer d'un bloc.
+pub mod prelude;
+/// Module de la vérification de la zone du tas.
+mod region;
+/// Module du rapport JSON des statistiques du tas.
+#[cfg(all(feature = "debug", feature = "stats"))]
+mod report;
+/// Module des réservations nommées de blocs.
+#[cfg(feature = "reservations")]
+mod reservation;
+/// Module des statistiques d'utilisation du tas.
+mod stats;
+/// Module des symboles exportés pour les débogueurs.
+#[cfg(feature = "gdb-symbols")]
+mod symbols;
+/// Module de la table des arènes, seul point d'accès à leur état.
+mod table;
+/// Module des derniers événements du tas, affichés en cas de panique.
+mod trace;
 /// Module pour les fonctions utilitaires (vide ou à compléter selon les besoins).
 mod utils;
 
+#[cfg(feature = "nightly")]
+use core::alloc::{AllocError, Allocator};
 use core::alloc::{GlobalAlloc, Layout};
-use memory::SlabMemory;
+#[cfg(feature = "nightly")]
+use core::ptr::NonNull;
+
+#[cfg(feature = "allocation-map")]
+pub use allocation_map::{AllocationEntry, AllocationMapError};
+#[cfg(feature = "debug")]
+pub use arena::FreeBlocks;
+#[cfg(feature = "caller-tags")]
+pub use callers::{LiveBlock, TrackingOverflow, MAX_TRACKED_CALLERS};
+pub use config::{ArenaConfig, BlockSize, InvalidBlockSize, ReusePolicy, ZeroSizePolicy};
+pub use error::AllocationError;
+pub use fatal::FatalPolicy;
+#[cfg(feature = "latency-budget")]
+pub use latency::LatencyBudget;
+#[cfg(feature = "magazines")]
+pub use magazine::{MAGAZINE_CONTEXTS, MAGAZINE_SIZE};
+pub use memory::SlabMemory;
+pub use pool::{FixedPool, PoolBlock};
+pub use region::RegionError;
+#[cfg(all(feature = "debug", feature = "stats"))]
+pub use report::REPORT_VERSION;
+#[cfg(feature = "reservations")]
+pub use reservation::{ReserveError, MAX_RESERVATIONS};
+#[cfg(feature = "stats")]
+pub use stats::{ClassStats, MemoryStats};
 
 /// Implémentation d'un allocateur global basé sur `SlabMemory`.
 ///
 /// Cet allocateur utilise une approche basée sur des arènes (slabs) pour gérer
-/// efficacement les allocations de tailles fixes. Il est configuré comme
-/// l'allocateur global à travers l'attribut `#[global_allocator]`.
+/// efficacement les allocations de tailles fixes. Il fonctionne sur Rust
+/// stable via [`GlobalAlloc`] ; avec la feature `nightly`, il implémente aussi
+/// le trait `Allocator` pour les collections qui acceptent un allocateur.
 ///
-/// # Exemple
+/// Avec la feature `global-allocator`, le crate le déclare lui-même comme
+/// allocateur global. Sinon, le programme le déclare ainsi :
 ///
-/// ```rust
-/// use my_allocator::GLOBAL_ALLOCATOR;
-/// use std::alloc::{alloc, dealloc, Layout};
+/// ```rust,no_run
+/// use global_allocator::SlabAllocator;
 ///
-/// let layout = Layout::from_size_align(32, 8).unwrap();
-/// unsafe {
-///     let ptr = alloc(layout);
-///     assert!(!ptr.is_null());
-///     dealloc(ptr, layout);
-/// }
+/// #[global_allocator]
+/// static ALLOCATOR: SlabAllocator = SlabAllocator;
 /// ```
+///
+/// Dans les deux cas, [`SlabMemory::initialize`] doit être appelé avant la
+/// première allocation.
 pub struct SlabAllocator;
 
 /// Déclare l'allocateur global pour le programme.
 ///
 /// Grâce à l'attribut `#[global_allocator]`, toutes les allocations dans
 /// le programme utilisent l'instance de `SlabAllocator` définie ici.
+///
+/// Disponible uniquement avec la feature `global-allocator`.
+#[cfg(feature = "global-allocator")]
 #[global_allocator]
 static GLOBAL_ALLOCATOR: SlabAllocator = SlabAllocator;
 
@@ -57,7 +148,7 @@ unsafe impl GlobalAlloc for SlabAllocator {
     /// L'appelant doit s'assurer que le pointeur retourné est utilisé correctement
     /// et désalloué via `dealloc` lorsqu'il n'est plus nécessaire.
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        SlabMemory::allocate(layout)
+        unsafe { SlabMemory::allocate(layout) }
     }
 
     /// Désalloue un bloc de mémoire précédemment alloué.
@@ -72,6 +163,89 @@ unsafe impl GlobalAlloc for SlabAllocator {
     /// L'appelant doit s'assurer que `ptr` est valide et a été précédemment
     /// alloué via cet allocateur.
     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
-        SlabMemory::deallocate(ptr, layout)
+        unsafe { SlabMemory::deallocate(ptr, layout) }
+    }
+
+    /// Redimensionne un bloc de mémoire précédemment alloué.
+    ///
+    /// Le bloc est gardé s'il reste dans la même catégorie, déplacé sinon
+    /// (voir [`SlabMemory::reallocate`]).
+    ///
+    /// # Safety
+    ///
+    /// `ptr` doit avoir été alloué par cet allocateur avec `layout`.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        unsafe { SlabMemory::reallocate(ptr, layout, new_size) }
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl Allocator for SlabAllocator {
+    /// Alloue un bloc de mémoire avec le layout spécifié.
+    ///
+    /// La tranche retournée couvre exactement `layout.size()` octets.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let block = unsafe { SlabMemory::allocate_non_null(layout) }.ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(block, layout.size()))
+    }
+
+    /// Désalloue un bloc de mémoire précédemment alloué.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` doit avoir été alloué par cet allocateur avec `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { SlabMemory::deallocate_non_null(ptr, layout) }
+    }
+
+    /// Agrandit un bloc, sur place s'il reste dans la même catégorie.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` doit avoir été alloué par cet allocateur avec `old_layout`, et
+    /// `new_layout` ne doit pas être plus petit.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let block = unsafe { SlabMemory::reallocate_non_null(ptr, old_layout, new_layout) };
+        let block = block.ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(block, new_layout.size()))
+    }
+
+    /// Agrandit un bloc et met à zéro les octets ajoutés.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Allocator::grow`].
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let block = unsafe { self.grow(ptr, old_layout, new_layout) }?;
+        let added = new_layout.size() - old_layout.size();
+        unsafe { block.cast::<u8>().add(old_layout.size()).write_bytes(0, added) };
+        Ok(block)
+    }
+
+    /// Réduit un bloc, sur place s'il reste dans la même catégorie.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` doit avoir été alloué par cet allocateur avec `old_layout`, et
+    /// `new_layout` ne doit pas être plus grand.
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let block = unsafe { SlabMemory::reallocate_non_null(ptr, old_layout, new_layout) };
+        let block = block.ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(block, new_layout.size()))
     }
 }
@@ -0,0 +1,150 @@
+use core::cell::UnsafeCell;
+use core::hint;
+use core::ops::{Deref, DerefMut};
+#[cfg(feature = "lock-free")]
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Un verrou tournant minimal protégeant une valeur.
+///
+/// Chaque arène possède son propre verrou, de sorte que des allocations dans
+/// des catégories différentes ne se bloquent jamais entre elles.
+///
+/// # Attention
+///
+/// Le verrou n'est pas réentrant et ne masque pas les interruptions : une
+/// routine d'interruption qui alloue dans une catégorie dont le verrou est
+/// tenu par le code qu'elle a interrompu bouclera indéfiniment sur un cœur
+/// unique.
+///
+/// Avec la feature `lock-free`, le verrou offre aussi un accès partagé
+/// ([`SpinLock::lock_shared`]) qui ne s'exclut pas lui-même. Il attend en
+/// revanche la fin d'un accès exclusif : la restriction ci-dessus vaut donc
+/// toujours pour une interruption qui survient pendant qu'un accès exclusif
+/// est tenu.
+pub struct SpinLock<T> {
+    /// Indique si le verrou est actuellement tenu.
+    locked: AtomicBool,
+    /// Nombre d'accès partagés en cours.
+    #[cfg(feature = "lock-free")]
+    shared: AtomicUsize,
+    /// Valeur protégée par le verrou.
+    value: UnsafeCell<T>,
+}
+
+// Le verrou garantit un accès exclusif à la valeur entre plusieurs contextes.
+#[cfg(not(feature = "lock-free"))]
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+// Les accès partagés donnent `&T` à plusieurs contextes à la fois.
+#[cfg(feature = "lock-free")]
+unsafe impl<T: Send + Sync> Sync for SpinLock<T> {}
+
+/// Garde donnant accès à la valeur d'un [`SpinLock`].
+///
+/// Le verrou est relâché lorsque la garde est détruite.
+pub struct SpinLockGuard<'a, T> {
+    /// Verrou tenu par cette garde.
+    lock: &'a SpinLock<T>,
+}
+
+/// Garde donnant un accès partagé à la valeur d'un [`SpinLock`].
+///
+/// Disponible uniquement avec la feature `lock-free`.
+#[cfg(feature = "lock-free")]
+pub struct SharedGuard<'a, T> {
+    /// Verrou dont l'accès partagé est tenu par cette garde.
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> SpinLock<T> {
+    /// Crée un nouveau verrou libre autour de `value`.
+    pub const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            #[cfg(feature = "lock-free")]
+            shared: AtomicUsize::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Acquiert le verrou en attendant activement qu'il se libère.
+    ///
+    /// Avec la feature `lock-free`, attend en plus la fin des accès partagés
+    /// en cours ; aucun nouvel accès partagé ne commence tant que la garde vit.
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::SeqCst, Ordering::Relaxed)
+            .is_err()
+        {
+            while self.locked.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+        #[cfg(feature = "lock-free")]
+        while self.shared.load(Ordering::SeqCst) != 0 {
+            hint::spin_loop();
+        }
+        SpinLockGuard { lock: self }
+    }
+
+    /// Obtient un accès partagé à la valeur.
+    ///
+    /// Des accès partagés simultanés, y compris depuis une interruption, ne se
+    /// bloquent jamais entre eux. Si un accès exclusif est en cours, attend
+    /// qu'il se termine : une interruption qui appelle cette méthode pendant
+    /// que le code interrompu tient le verrou boucle indéfiniment sur un cœur
+    /// unique.
+    ///
+    /// Disponible uniquement avec la feature `lock-free`.
+    #[cfg(feature = "lock-free")]
+    pub fn lock_shared(&self) -> SharedGuard<'_, T> {
+        loop {
+            self.shared.fetch_add(1, Ordering::SeqCst);
+            if !self.locked.load(Ordering::SeqCst) {
+                return SharedGuard { lock: self };
+            }
+            self.shared.fetch_sub(1, Ordering::Release);
+            while self.locked.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+    }
+}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(feature = "lock-free")]
+impl<T> Deref for SharedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+#[cfg(feature = "lock-free")]
+impl<T> Drop for SharedGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.shared.fetch_sub(1, Ordering::Release);
+    }
+}
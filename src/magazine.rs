@@ -0,0 +1,76 @@
+use core::ptr::NonNull;
+
+/// Nombre de contextes pouvant posséder leurs propres magasins.
+///
+/// Les identifiants de contexte valides vont de `0` à `MAGAZINE_CONTEXTS - 1`.
+pub const MAGAZINE_CONTEXTS: usize = 4;
+
+/// Nombre maximal de blocs gardés en réserve par magasin.
+pub const MAGAZINE_SIZE: usize = 8;
+
+/// Petite réserve de blocs d'une catégorie, propre à un contexte.
+///
+/// Un magasin est rempli et vidé par lots de `MAGAZINE_SIZE / 2` blocs, de
+/// sorte que des allocations et libérations alternées dans un même contexte
+/// ne touchent jamais l'arène partagée.
+pub struct Magazine {
+    /// Blocs en réserve ; seuls les `len` premiers sont valides.
+    blocks: [NonNull<u8>; MAGAZINE_SIZE],
+    /// Nombre de blocs en réserve.
+    len: usize,
+}
+
+// Un magasin ne contient que des blocs libres qui lui appartiennent.
+unsafe impl Send for Magazine {}
+
+// Un magasin n'est jamais accédé sans son verrou.
+unsafe impl Sync for Magazine {}
+
+impl Magazine {
+    /// Crée un magasin vide.
+    pub const fn new() -> Self {
+        Self {
+            blocks: [NonNull::dangling(); MAGAZINE_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Retire un bloc du magasin, s'il en contient.
+    pub fn pop(&mut self) -> Option<NonNull<u8>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.blocks[self.len])
+    }
+
+    /// Ajoute un bloc au magasin.
+    ///
+    /// Retourne `false` si le magasin est plein.
+    pub fn push(&mut self, block: NonNull<u8>) -> bool {
+        if self.len == MAGAZINE_SIZE {
+            return false;
+        }
+        self.blocks[self.len] = block;
+        self.len += 1;
+        true
+    }
+
+    /// Retourne la partie libre du magasin, à remplir par un lot.
+    pub fn spare(&mut self) -> &mut [NonNull<u8>] {
+        &mut self.blocks[self.len..]
+    }
+
+    /// Valide `count` blocs écrits au début de [`Magazine::spare`].
+    pub fn commit(&mut self, count: usize) {
+        self.len += count;
+    }
+
+    /// Retire au plus `count` blocs du magasin et les retourne.
+    pub fn take(&mut self, count: usize) -> &[NonNull<u8>] {
+        let start = self.len.saturating_sub(count);
+        let end = self.len;
+        self.len = start;
+        &self.blocks[start..end]
+    }
+}
@@ -1,8 +1,53 @@
 use core::alloc::Layout;
-use core::mem::MaybeUninit;
+use core::ops::Range;
+#[cfg(feature = "debug")]
 use core::fmt::Write;
-use crate::arena::Arena;
-use crate::config::BlockSize;
+use core::ptr::NonNull;
+#[cfg(feature = "fault-injection")]
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::{AtomicU8, Ordering};
+use crate::alignment;
+use crate::allocation_map;
+#[cfg(feature = "caller-tags")]
+use crate::callers::{LiveBlock, TrackingOverflow};
+#[cfg(feature = "allocation-map")]
+use crate::allocation_map::{AllocationEntry, AllocationMapError};
+use crate::arena::{Arena, FreeChain};
+#[cfg(feature = "debug")]
+use crate::arena::INDEX_MASK;
+use crate::callers;
+#[cfg(feature = "debug")]
+use crate::arena::FreeBlocks;
+#[cfg(feature = "debug")]
+use crate::dump::{self, DumpSink};
+#[cfg(feature = "metrics")]
+use crate::metrics;
+#[cfg(all(feature = "debug", feature = "stats"))]
+use crate::report;
+use crate::config::{ArenaConfig, BlockSize, ReusePolicy, ZeroSizePolicy};
+#[cfg(feature = "debug")]
+use crate::debug::{self, DebugWriter};
+use crate::error::AllocationError;
+use crate::fatal::{self, FatalPolicy};
+use crate::header::{self, Detached, HEADER_SIZE};
+use crate::latency;
+#[cfg(feature = "latency-budget")]
+use crate::latency::LatencyBudget;
+#[cfg(any(feature = "magazines", feature = "reservations"))]
+use crate::lock::SpinLock;
+#[cfg(feature = "magazines")]
+use crate::magazine::{Magazine, MAGAZINE_CONTEXTS, MAGAZINE_SIZE};
+#[cfg(feature = "reservations")]
+use crate::reservation::{ReserveError, Reservation, MAX_RESERVATIONS};
+use crate::region::{self, RegionError};
+use crate::stats;
+use crate::table::ArenaTable;
+use crate::trace;
+use crate::utils::{align_to, env_usize};
+#[cfg(feature = "gdb-symbols")]
+use crate::symbols;
+#[cfg(feature = "stats")]
+use crate::stats::MemoryStats;
 
 /// Gestionnaire de mémoire utilisant une approche basée sur les slabs.
 ///
@@ -11,47 +56,246 @@ use crate::config::BlockSize;
 /// et efficace pour des tailles spécifiques.
 pub struct SlabMemory;
 
-/// Tableau contenant les arènes. Chaque arène gère des blocs de taille fixe.
+// Garanties d'alignement vérifiées à la compilation, pour toute valeur de
+// `SLAB_MIN_ALIGN` : chaque bloc commence sur un multiple de `MIN_ALIGN`,
+// qui doit suffire à y ranger le pointeur de la liste des blocs libres, et
+// l'en-tête ne doit pas décaler le pointeur retourné de cet alignement.
+const _: () = {
+    let align = SlabMemory::MIN_ALIGN;
+    assert!(align.is_power_of_two(), "SLAB_MIN_ALIGN must be a power of two");
+    assert!(align <= BlockSize::MIN, "SLAB_MIN_ALIGN must not exceed the smallest block size");
+    assert!(
+        align >= core::mem::align_of::<*mut u8>(),
+        "SLAB_MIN_ALIGN must be at least the alignment of a pointer"
+    );
+    assert!(
+        HEADER_SIZE.is_multiple_of(align),
+        "SLAB_MIN_ALIGN must divide the block header size"
+    );
+    let mut i = 0;
+    while i < BlockSize::COUNT {
+        assert!(
+            BlockSize::ALL[i].size().is_multiple_of(align),
+            "SLAB_MIN_ALIGN must divide every block size"
+        );
+        i += 1;
+    }
+};
+
+/// Table des arènes. Chaque arène gère des blocs de taille fixe.
 ///
-/// Le tableau est initialisé dynamiquement à l'aide de `MaybeUninit`, car
-/// `Option<Arena>` n'implémente pas `Copy`.
-static mut ARENAS: MaybeUninit<[Option<Arena>; 8]> = MaybeUninit::uninit();
+/// Chaque arène est protégée par son propre verrou : des allocations dans des
+/// catégories différentes ne se bloquent jamais. Les arènes valent `None`
+/// tant que [`SlabMemory::initialize`] n'a pas été appelé.
+static ARENAS: ArenaTable = ArenaTable::new();
+
+/// Politique appliquée aux demandes de taille nulle, en valeur `repr(u8)`.
+static ZERO_SIZE: AtomicU8 = AtomicU8::new(ZeroSizePolicy::Dangling as u8);
+
+/// Catégories dont les demandes peuvent être servies par la catégorie
+/// suivante lorsqu'elles sont épuisées, un bit par index.
+static FALLBACKS: AtomicU8 = AtomicU8::new(0);
 
-/// Structure pour écrire des messages de débogage.
+/// Catégories dont l'arène réutilise ses blocs en [`ReusePolicy::Fifo`], un
+/// bit par index.
 ///
-/// Implémente le trait [`core::fmt::Write`] pour permettre des sorties
-/// formatées via `write!` ou `writeln!`.
-struct DebugWriter;
+/// Appliqué à chaque arène lors de l'initialisation.
+static FIFO_REUSE: AtomicU8 = AtomicU8::new(0);
 
-impl Write for DebugWriter {
-    /// Écrit une chaîne de caractères dans un périphérique ou un buffer.
-    ///
-    /// # Safety
-    ///
-    /// Cette méthode utilise une fonction externe [`debug_putchar`] pour
-    /// gérer la sortie des caractères.
-    fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        for c in s.bytes() {
-            unsafe { debug_putchar(c) };
+/// Nombre d'échecs d'allocation à simuler pour chaque catégorie de bloc.
+///
+/// Indexé comme [`ARENAS`]. Chaque allocation dans une catégorie dont le
+/// compteur est non nul échoue et décrémente ce compteur.
+#[cfg(feature = "fault-injection")]
+static FAIL_NEXT: [AtomicUsize; 8] = [const { AtomicUsize::new(0) }; 8];
+
+/// Magasins de blocs de chaque contexte, indexés par contexte puis par catégorie.
+///
+/// Un contexte n'accède qu'à ses propres magasins : leurs verrous ne sont
+/// jamais disputés tant que chaque identifiant de contexte n'est utilisé que
+/// par un seul contexte d'exécution à la fois.
+#[cfg(feature = "magazines")]
+static MAGAZINES: [[SpinLock<Magazine>; 8]; MAGAZINE_CONTEXTS] =
+    [const { [const { SpinLock::new(Magazine::new()) }; 8] }; MAGAZINE_CONTEXTS];
+
+/// Réservations nommées créées par [`SlabMemory::reserve`].
+///
+/// Les réservations sont verrouillées avant les arènes, jamais l'inverse.
+#[cfg(feature = "reservations")]
+static RESERVATIONS: SpinLock<[Option<Reservation>; MAX_RESERVATIONS]> =
+    SpinLock::new([const { None }; MAX_RESERVATIONS]);
+
+/// Classement d'une demande avant tout accès aux arènes, par
+/// [`SlabMemory::classify`].
+enum Admission {
+    /// Demande de taille nulle servie par un pointeur pendant, sans bloc.
+    Dangling,
+    /// Demande refusée : taille nulle sous [`ZeroSizePolicy::Null`], taille
+    /// hors catégorie ou allocateur empoisonné.
+    Refused(AllocationError),
+    /// Demande à servir par un bloc de cette catégorie.
+    Block(BlockSize),
+}
+
+/// Accès à une arène accordé par [`with_arena`].
+#[cfg(feature = "lock-free")]
+enum Access<'a> {
+    /// Accès partagé, sans exclusion : l'arène est en [`ReusePolicy::Lifo`].
+    Shared(&'a Arena),
+    /// Accès exclusif sous le verrou de l'arène.
+    Exclusive(&'a mut Arena),
+}
+
+/// Applique `f` à l'arène d'index `index`, ou retourne `R::default()` si elle
+/// n'est pas initialisée.
+///
+/// Les méthodes partagées ne savent retirer et remettre des blocs qu'en tête
+/// de liste : une arène en [`ReusePolicy::Fifo`] est modifiée sous son
+/// verrou. La politique est lue sous l'accès partagé, ce qui empêche qu'elle
+/// change avant la fin de l'opération. Dans les deux cas, l'opération attend
+/// la fin d'un accès exclusif en cours.
+#[cfg(feature = "lock-free")]
+fn with_arena<R: Default>(index: usize, f: impl FnOnce(Access<'_>) -> R) -> R {
+    {
+        let arena = ARENAS.lock_shared(index);
+        match arena.as_ref() {
+            None => return R::default(),
+            Some(arena) if arena.reuse_policy() == ReusePolicy::Lifo => return f(Access::Shared(arena)),
+            Some(_) => {}
         }
-        Ok(())
+    }
+    ARENAS.lock(index).as_mut().map_or_else(R::default, |arena| f(Access::Exclusive(arena)))
+}
+
+/// Retire un bloc d'une arène, ou retourne `None` si elle est vide ou n'est
+/// pas initialisée.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`SlabMemory::allocate`].
+unsafe fn pop_block(index: usize) -> Option<NonNull<u8>> {
+    #[cfg(not(feature = "lock-free"))]
+    let block = ARENAS.lock(index).as_mut().and_then(|arena| unsafe { arena.allocate() });
+    #[cfg(feature = "lock-free")]
+    let block = with_arena(index, |access| match access {
+        Access::Shared(arena) => unsafe { arena.allocate_shared() },
+        Access::Exclusive(arena) => unsafe { arena.allocate() },
+    });
+    #[cfg(feature = "paranoid")]
+    check_arena(index);
+    block
+}
+
+/// Remet un bloc dans une arène.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`SlabMemory::deallocate`].
+unsafe fn push_block(index: usize, block: NonNull<u8>) {
+    #[cfg(not(feature = "lock-free"))]
+    if let Some(ref mut arena) = *ARENAS.lock(index) {
+        unsafe { arena.deallocate(block) };
+    }
+    #[cfg(feature = "lock-free")]
+    with_arena(index, |access| match access {
+        Access::Shared(arena) => unsafe { arena.deallocate_shared(block) },
+        Access::Exclusive(arena) => unsafe { arena.deallocate(block) },
+    });
+    #[cfg(feature = "paranoid")]
+    check_arena(index);
+}
+
+/// Retire jusqu'à `out.len()` blocs d'une arène sous une seule acquisition de
+/// son verrou.
+///
+/// Retourne le nombre de blocs écrits au début de `out`.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`SlabMemory::allocate`] pour chaque bloc retiré.
+unsafe fn pop_blocks(index: usize, out: &mut [NonNull<u8>]) -> usize {
+    #[cfg(not(feature = "lock-free"))]
+    let count = ARENAS.lock(index).as_mut().map_or(0, |arena| unsafe { arena.allocate_many(out) });
+    #[cfg(feature = "lock-free")]
+    let count = with_arena(index, |access| match access {
+        Access::Shared(arena) => unsafe { arena.allocate_many_shared(out) },
+        Access::Exclusive(arena) => unsafe { arena.allocate_many(out) },
+    });
+    #[cfg(feature = "paranoid")]
+    check_arena(index);
+    count
+}
+
+/// Raccorde une chaîne de blocs à une arène sous une seule acquisition de son
+/// verrou.
+///
+/// # Safety
+///
+/// Mêmes conditions que [`SlabMemory::deallocate`] pour chaque bloc.
+unsafe fn push_chain(index: usize, chain: FreeChain) {
+    #[cfg(not(feature = "lock-free"))]
+    if let Some(ref mut arena) = *ARENAS.lock(index) {
+        unsafe { arena.splice(chain) };
+    }
+    #[cfg(feature = "lock-free")]
+    with_arena(index, |access| match access {
+        Access::Shared(arena) => unsafe { arena.splice_shared(chain) },
+        Access::Exclusive(arena) => unsafe { arena.splice(chain) },
+    });
+    #[cfg(feature = "paranoid")]
+    check_arena(index);
+}
+
+/// Vérifie la liste des blocs libres d'une arène après une opération.
+///
+/// Une incohérence est traitée comme une corruption (voir [`FatalPolicy`]) :
+/// elle est signalée au plus près de l'opération qui l'a causée. Disponible
+/// uniquement avec la feature `paranoid`, destinée aux tests d'endurance.
+///
+/// La vérification prend le verrou exclusif de l'arène : avec `lock-free`,
+/// chaque opération peut donc attendre celle d'un autre contexte.
+#[cfg(feature = "paranoid")]
+fn check_arena(index: usize) {
+    let result = ARENAS.lock(index).as_ref().map_or(Ok(()), Arena::check);
+    if let Err(node) = result {
+        fatal::corruption("Free list invariant violated", node);
     }
 }
 
-/// Fonction simulant l'envoi de caractères pour le débogage.
+/// Remet des blocs dans une arène sous une seule acquisition de son verrou.
 ///
-/// Cette fonction est un point d'entrée pour rediriger les sorties vers un
-/// périphérique, comme un port série ou un buffer. Par défaut, elle est vide.
+/// La chaîne des blocs est construite avant de prendre le verrou.
 ///
 /// # Safety
 ///
-/// L'appelant doit s'assurer que la sortie configurée est prête à recevoir
-/// des données.
-unsafe fn debug_putchar(byte: u8) {
-    // Implémentez ici la logique pour écrire sur un périphérique.
+/// Mêmes conditions que [`SlabMemory::deallocate`] pour chaque bloc.
+#[cfg(feature = "magazines")]
+unsafe fn push_blocks(index: usize, blocks: &[NonNull<u8>]) {
+    let mut chain = FreeChain::new();
+    for &block in blocks {
+        unsafe { chain.push(block) };
+    }
+    unsafe { push_chain(index, chain) };
 }
 
 impl SlabMemory {
+    /// Alignement minimal, en octets, de tout pointeur retourné par l'allocateur.
+    ///
+    /// Toutes les tailles de bloc et l'en-tête (feature `block-header`) en
+    /// sont des multiples, et [`SlabMemory::initialize_with`] aligne le début
+    /// du tas dessus. Une allocation demandant un alignement supérieur n'est
+    /// servie que si les pointeurs de sa catégorie le garantissent, et échoue
+    /// sinon. Sans la feature `block-header`, un tas aligné sur
+    /// [`BlockSize::MAX`] aligne chaque pointeur sur la taille de son bloc ;
+    /// avec elle, le pointeur suit l'en-tête et n'est aligné que sur
+    /// les 8 octets de l'en-tête, quel que soit le tas.
+    ///
+    /// Fixé à la compilation par la variable d'environnement `SLAB_MIN_ALIGN`
+    /// (8 par défaut). La valeur doit être une puissance de deux, au moins
+    /// l'alignement d'un pointeur et au plus [`BlockSize::MIN`] ; toute autre
+    /// valeur est une erreur de compilation.
+    pub const MIN_ALIGN: usize = env_usize!("SLAB_MIN_ALIGN", 8);
+
     /// Alloue un bloc de mémoire basé sur le layout spécifié.
     ///
     /// Recherche une arène (`Arena`) adaptée à la taille demandée et retourne
@@ -71,15 +315,254 @@ impl SlabMemory {
     /// L'appelant doit s'assurer que le pointeur retourné est utilisé
     /// correctement et désalloué lorsqu'il n'est plus nécessaire.
     pub unsafe fn allocate(layout: Layout) -> *mut u8 {
-        let arenas = ARENAS.assume_init_mut();
+        unsafe { Self::allocate_non_null(layout) }.map_or(core::ptr::null_mut(), NonNull::as_ptr)
+    }
+
+    /// Alloue un bloc de mémoire basé sur le layout spécifié.
+    ///
+    /// Identique à [`SlabMemory::allocate`], mais retourne `None` en cas d'échec.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::allocate`].
+    pub unsafe fn allocate_non_null(layout: Layout) -> Option<NonNull<u8>> {
+        unsafe { Self::try_allocate(layout) }.ok()
+    }
+
+    /// Alloue un bloc de mémoire basé sur le layout spécifié.
+    ///
+    /// Identique à [`SlabMemory::allocate`], mais retourne la raison de
+    /// l'échec, convertible en code stable pour une interface FFI.
+    ///
+    /// # Returns
+    ///
+    /// Le bloc alloué, ou une [`AllocationError`].
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::allocate`].
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::{AllocationError, SlabMemory};
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    ///
+    /// let layout = Layout::from_size_align(2048, 8).unwrap();
+    /// let result = unsafe { SlabMemory::try_allocate(layout) };
+    /// assert_eq!(result, Err(AllocationError::TooLarge));
+    /// assert_eq!(result.map_err(i16::from), Err(-2));
+    /// ```
+    pub unsafe fn try_allocate(layout: Layout) -> Result<NonNull<u8>, AllocationError> {
+        let block_size = match Self::classify(layout) {
+            // L'alignement d'un layout n'est jamais nul.
+            Admission::Dangling => {
+                return Ok(unsafe { NonNull::new_unchecked(core::ptr::without_provenance_mut(layout.align())) });
+            }
+            Admission::Refused(error) => return Err(error),
+            Admission::Block(block_size) => block_size,
+        };
+        let _stopwatch = latency::Stopwatch::start(block_size);
+
+        if let Err(error) = Self::admit(block_size, layout, true) {
+            unsafe { Self::finish_allocation(block_size, None) };
+            return Err(error);
+        }
+
+        let (block_size, block) = match unsafe { pop_block(block_size.index()) } {
+            None => unsafe { Self::pop_fallback(block_size, layout) },
+            block => (block_size, block),
+        };
+        if block.is_none() {
+            allocation_map::release(layout);
+        }
+        unsafe { Self::finish_allocation(block_size, block) }.ok_or(AllocationError::OutOfMemory)
+    }
+
+    /// Classe une demande avant tout accès aux arènes : taille nulle (voir
+    /// [`ZeroSizePolicy`]), taille hors catégorie, allocateur empoisonné.
+    fn classify(layout: Layout) -> Admission {
+        if layout.size() == 0 {
+            match Self::zero_size_policy() {
+                ZeroSizePolicy::Dangling => return Admission::Dangling,
+                ZeroSizePolicy::Null => return Admission::Refused(AllocationError::ZeroSize),
+                ZeroSizePolicy::Block => {}
+            }
+        }
+        match BlockSize::categorize(header::block_request(layout.size())) {
+            None => Admission::Refused(AllocationError::TooLarge),
+            Some(_) if fatal::is_poisoned() => Admission::Refused(AllocationError::Poisoned),
+            Some(block_size) => Admission::Block(block_size),
+        }
+    }
+
+    /// Vérifie qu'une demande de `layout` peut être servie par `block_size` :
+    /// alignement, échec injecté (feature `fault-injection`) et plan
+    /// d'allocation (feature `allocation-map`).
+    ///
+    /// Avec `commit`, la demande est enregistrée : son alignement est audité,
+    /// l'échec injecté est consommé et sa place est prise dans le plan. Sans
+    /// `commit`, rien n'est modifié.
+    fn admit(block_size: BlockSize, layout: Layout, commit: bool) -> Result<(), AllocationError> {
+        if !commit {
+            #[cfg(feature = "fault-injection")]
+            if FAIL_NEXT[block_size.index()].load(Ordering::Relaxed) > 0 {
+                return Err(AllocationError::Injected);
+            }
+            if layout.align() > ARENAS.alignment(block_size.index()) {
+                return Err(AllocationError::Misaligned);
+            }
+            if !allocation_map::would_admit(layout) {
+                return Err(AllocationError::OutsideAllocationMap);
+            }
+            return Ok(());
+        }
+        if !Self::aligned_for(block_size, layout) {
+            return Err(AllocationError::Misaligned);
+        }
+        #[cfg(feature = "fault-injection")]
+        if Self::consume_injected_failure(block_size) {
+            return Err(AllocationError::Injected);
+        }
+        if !allocation_map::admit(layout) {
+            return Err(AllocationError::OutsideAllocationMap);
+        }
+        Ok(())
+    }
+
+    /// Retourne la catégorie de repli de `block_size` si le repli est activé
+    /// pour elle (voir [`SlabMemory::set_fallback`]) et que ses pointeurs
+    /// respectent l'alignement de `layout`.
+    fn fallback_for(block_size: BlockSize, layout: Layout) -> Option<BlockSize> {
+        Self::fallback_of(block_size).filter(|larger| layout.align() <= ARENAS.alignment(larger.index()))
+    }
 
-        if let Some(block_size) = BlockSize::categorize(layout.size()) {
-            let index = block_size as usize / 8 - 1;
-            if let Some(ref mut arena) = arenas[index] {
-                return arena.allocate();
+    /// Retire un bloc de la catégorie de repli de `block_size` (voir
+    /// [`SlabMemory::fallback_for`]).
+    ///
+    /// # Returns
+    ///
+    /// La catégorie du bloc obtenu et le bloc, ou `block_size` et `None`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::allocate`].
+    unsafe fn pop_fallback(block_size: BlockSize, layout: Layout) -> (BlockSize, Option<NonNull<u8>>) {
+        let Some(larger) = Self::fallback_for(block_size, layout) else {
+            return (block_size, None);
+        };
+        match unsafe { pop_block(larger.index()) } {
+            Some(block) => {
+                stats::record_fallback(block_size.index());
+                (larger, Some(block))
             }
+            None => (block_size, None),
+        }
+    }
+
+    /// Indique si les pointeurs de `block_size` respectent l'alignement de
+    /// `layout`, et enregistre la demande pour la feature `alignment-audit`.
+    fn aligned_for(block_size: BlockSize, layout: Layout) -> bool {
+        let guaranteed = ARENAS.alignment(block_size.index());
+        alignment::audit(block_size, layout, guaranteed);
+        layout.align() <= guaranteed
+    }
+
+    /// Retourne la catégorie de repli de `block_size`, ou `None` si le repli
+    /// n'est pas activé pour elle.
+    fn fallback_of(block_size: BlockSize) -> Option<BlockSize> {
+        if FALLBACKS.load(Ordering::Relaxed) & (1 << block_size.index()) == 0 {
+            return None;
+        }
+        BlockSize::from_index(block_size.index() + 1)
+    }
+
+    /// Alloue jusqu'à `out.len()` blocs de `layout` sous une seule
+    /// acquisition du verrou de l'arène.
+    ///
+    /// Destiné aux pilotes qui remplissent plusieurs tampons à la fois (par
+    /// exemple les tampons de réception d'une interface réseau à chaque
+    /// interruption). Si l'arène se vide en cours de route, les blocs déjà
+    /// obtenus sont conservés et un seul échec est compté.
+    ///
+    /// # Arguments
+    ///
+    /// - `layout`: Taille et alignement de chaque bloc.
+    /// - `out`: Emplacements recevant les blocs alloués.
+    ///
+    /// # Returns
+    ///
+    /// Le nombre de blocs écrits au début de `out`. Ils se libèrent avec
+    /// [`SlabMemory::deallocate`] ou [`SlabMemory::deallocate_batch`] et `layout`.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::allocate`] pour chaque bloc.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use core::ptr::NonNull;
+    /// use global_allocator::SlabMemory;
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    ///
+    /// let layout = Layout::from_size_align(256, 8).unwrap();
+    /// let mut buffers = [NonNull::dangling(); 4];
+    /// unsafe {
+    ///     let count = SlabMemory::allocate_batch(layout, &mut buffers);
+    ///     assert_eq!(count, 4);
+    ///     SlabMemory::deallocate_batch(layout, &buffers[..count]);
+    /// }
+    /// ```
+    pub unsafe fn allocate_batch(layout: Layout, out: &mut [NonNull<u8>]) -> usize {
+        if layout.size() == 0 && Self::zero_size_policy() != ZeroSizePolicy::Block {
+            return out
+                .iter_mut()
+                .map_while(|slot| unsafe { Self::allocate_non_null(layout) }.map(|ptr| *slot = ptr))
+                .count();
+        }
+        let Some(block_size) = BlockSize::categorize(header::block_request(layout.size())) else {
+            return 0;
+        };
+        if fatal::is_poisoned() {
+            return 0;
+        }
+        let _stopwatch = latency::Stopwatch::start(block_size);
+
+        if !Self::aligned_for(block_size, layout) {
+            unsafe { Self::finish_allocation(block_size, None) };
+            return 0;
+        }
+        #[cfg(feature = "fault-injection")]
+        if Self::consume_injected_failure(block_size) {
+            unsafe { Self::finish_allocation(block_size, None) };
+            return 0;
+        }
+        let admitted = out.iter().take_while(|_| allocation_map::admit(layout)).count();
+
+        let count = unsafe { pop_blocks(block_size.index(), &mut out[..admitted]) };
+        for _ in count..admitted {
+            allocation_map::release(layout);
         }
-        core::ptr::null_mut()
+        for block in &mut out[..count] {
+            *block = unsafe { Self::finish_allocation(block_size, Some(*block)) }.unwrap();
+        }
+        if count < out.len() {
+            unsafe { Self::finish_allocation(block_size, None) };
+        }
+        count
     }
 
     /// Désalloue un bloc de mémoire précédemment alloué.
@@ -94,64 +577,1453 @@ impl SlabMemory {
     /// # Safety
     ///
     /// L'appelant doit s'assurer que `ptr` est un pointeur valide qui a été
-    /// obtenu via [`SlabMemory::allocate`].
+    /// obtenu via [`SlabMemory::allocate`]. Un pointeur nul, ou qui ne désigne
+    /// aucun bloc (hors de toute arène ou à l'intérieur d'un bloc), est
+    /// ignoré, compté et signalé sur la sortie de débogage ; un pointeur qui
+    /// ne désigne aucun bloc déclenche en outre la [`FatalPolicy`] installée.
     pub unsafe fn deallocate(ptr: *mut u8, layout: Layout) {
-        let arenas = ARENAS.assume_init_mut();
+        match NonNull::new(ptr) {
+            Some(ptr) => unsafe { Self::deallocate_non_null(ptr, layout) },
+            None => Self::report_null_free(),
+        }
+    }
+
+    /// Désalloue un bloc de mémoire précédemment alloué.
+    ///
+    /// Identique à [`SlabMemory::deallocate`], pour un pointeur non nul.
+    ///
+    /// # Safety
+    ///
+    /// L'appelant doit s'assurer que `ptr` a été obtenu via
+    /// [`SlabMemory::allocate`] ou [`SlabMemory::allocate_non_null`].
+    pub unsafe fn deallocate_non_null(ptr: NonNull<u8>, layout: Layout) {
+        if let Some((index, block)) = unsafe { Self::begin_deallocation(ptr, layout) } {
+            unsafe { push_block(index, block) };
+        }
+    }
 
-        if let Some(block_size) = BlockSize::categorize(layout.size()) {
-            let index = block_size as usize / 8 - 1;
-            if let Some(ref mut arena) = arenas[index] {
-                arena.deallocate(ptr);
+    /// Libère d'un coup des blocs alloués avec le même `layout`.
+    ///
+    /// Les blocs sont chaînés entre eux sans verrou, puis la chaîne est
+    /// raccordée à la liste des blocs libres en temps constant sous une seule
+    /// acquisition du verrou de l'arène. Destiné aux libérations groupées,
+    /// par exemple les tampons d'une connexion qui se ferme.
+    ///
+    /// Chaque bloc est vérifié comme par [`SlabMemory::deallocate`] ; un bloc
+    /// qui appartient à une autre catégorie est rendu à son arène d'origine.
+    ///
+    /// # Arguments
+    ///
+    /// - `layout`: Taille et alignement communs des blocs.
+    /// - `blocks`: Blocs à libérer.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::deallocate_non_null`] pour chaque
+    /// bloc. Un même bloc ne doit pas figurer deux fois dans `blocks`.
+    pub unsafe fn deallocate_batch(layout: Layout, blocks: &[NonNull<u8>]) {
+        let mut chain = FreeChain::new();
+        let mut chain_index = None;
+        for &ptr in blocks {
+            let Some((index, block)) = (unsafe { Self::begin_deallocation(ptr, layout) }) else {
+                continue;
+            };
+            if *chain_index.get_or_insert(index) == index {
+                unsafe { chain.push(block) };
+            } else {
+                unsafe { push_block(index, block) };
             }
         }
+        if let Some(index) = chain_index {
+            unsafe { push_chain(index, chain) };
+        }
     }
 
-    /// Initialise les arènes avec un espace mémoire donné.
+    /// Redimensionne un bloc, comme `GlobalAlloc::realloc`.
     ///
-    /// Divise la mémoire en blocs de tailles fixes et configure les arènes
-    /// correspondantes. Chaque arène est associée à une taille de bloc spécifique.
+    /// Si la nouvelle taille relève de la même catégorie, le bloc est gardé
+    /// tel quel ; sinon son contenu est déplacé dans un bloc de la nouvelle
+    /// catégorie, jusqu'à la plus petite des deux tailles, et l'ancien bloc
+    /// est libéré. L'appelant enregistré avec le bloc (feature `caller-tags`)
+    /// est conservé.
     ///
     /// # Arguments
     ///
-    /// - `heap_start`: Adresse de début de la mémoire gérée.
-    /// - `heap_size`: Taille totale de la mémoire.
+    /// - `ptr`: Bloc à redimensionner, ou `null` pour une simple allocation.
+    /// - `layout`: Layout avec lequel le bloc a été alloué.
+    /// - `new_size`: Nouvelle taille, en octets ; l'alignement est inchangé.
+    ///
+    /// # Returns
+    ///
+    /// Le bloc redimensionné, ou `null_mut` en cas d'échec : l'ancien bloc
+    /// reste alors valide et inchangé.
     ///
     /// # Safety
     ///
-    /// L'appelant doit s'assurer que `heap_start` pointe vers une zone de
-    /// mémoire valide et accessible, et que `heap_size` est suffisant pour
-    /// initialiser toutes les arènes.
-    pub unsafe fn initialize(heap_start: *mut u8, heap_size: usize) {
-        let mut temp_arenas: [Option<Arena>; 8] = [None, None, None, None, None, None, None, None];
-        let block_count = heap_size / temp_arenas.len();
-        let mut current = heap_start;
+    /// Mêmes conditions que [`SlabMemory::deallocate`] pour `ptr` non nul.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::{BlockSize, SlabMemory};
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe {
+    ///     SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len());
+    ///     let layout = Layout::from_size_align(24, 8).unwrap();
+    ///     let ptr = SlabMemory::allocate(layout);
+    ///     ptr.write(42);
+    ///     let ptr = SlabMemory::reallocate(ptr, layout, 200);
+    ///     assert_eq!(ptr.read(), 42);
+    ///     assert_eq!(SlabMemory::arena_of(ptr), Some(BlockSize::Giant));
+    ///     SlabMemory::deallocate(ptr, Layout::from_size_align(200, 8).unwrap());
+    /// }
+    /// ```
+    pub unsafe fn reallocate(ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let Ok(new_layout) = Layout::from_size_align(new_size, layout.align()) else {
+            return core::ptr::null_mut();
+        };
+        let block = match NonNull::new(ptr) {
+            Some(ptr) => unsafe { Self::reallocate_non_null(ptr, layout, new_layout) },
+            None => unsafe { Self::allocate_non_null(new_layout) },
+        };
+        block.map_or(core::ptr::null_mut(), NonNull::as_ptr)
+    }
 
-        for i in 0..temp_arenas.len() {
-            let block_size = (1 << (3 + i)) as usize; // 8, 16, 32, ...
-            temp_arenas[i] = Some(Arena::new(current, block_count, block_size));
-            current = current.add(block_count * block_size);
+    /// Redimensionne un bloc vers un nouveau layout.
+    ///
+    /// Identique à [`SlabMemory::reallocate`], pour un pointeur non nul et un
+    /// layout complet : l'alignement peut changer, comme pour
+    /// `Allocator::grow` et `Allocator::shrink`. Retourne `None` en cas
+    /// d'échec.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::deallocate_non_null`].
+    pub unsafe fn reallocate_non_null(
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        if fatal::is_poisoned() {
+            return None;
+        }
+        if new_layout == layout {
+            return Some(ptr);
         }
+        let class = BlockSize::categorize(header::block_request(layout.size()));
+        let new_class = BlockSize::categorize(header::block_request(new_layout.size()))?;
+        let in_place = layout.size() != 0
+            && new_layout.size() != 0
+            && class == Some(new_class)
+            && ptr.as_ptr().addr().is_multiple_of(new_layout.align());
+        if !in_place {
+            return unsafe { Self::move_block(ptr, layout, new_layout) };
+        }
+        if !allocation_map::admit(new_layout) {
+            return None;
+        }
+        allocation_map::release(layout);
+        Some(ptr)
+    }
 
-        ARENAS.write(temp_arenas);
+    /// Déplace le contenu d'un bloc dans un nouveau bloc de `new_layout` et
+    /// libère l'ancien.
+    ///
+    /// Seul chemin par lequel un bloc change de catégorie : le nouveau bloc
+    /// est alloué puis l'ancien libéré par les chemins ordinaires (allocateur
+    /// empoisonné, statistiques, plan d'allocation, vérification de
+    /// l'en-tête), les `min(layout.size(), new_layout.size())` premiers octets
+    /// sont copiés et l'appelant enregistré avec l'ancien bloc passe au
+    /// nouveau.
+    ///
+    /// # Returns
+    ///
+    /// Le nouveau bloc, ou `None` si aucun bloc de `new_layout` n'est
+    /// disponible : l'ancien bloc reste alors valide.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::deallocate_non_null`].
+    unsafe fn move_block(ptr: NonNull<u8>, layout: Layout, new_layout: Layout) -> Option<NonNull<u8>> {
+        let block = unsafe { Self::allocate_non_null(new_layout) }?;
+        let count = layout.size().min(new_layout.size());
+        unsafe { core::ptr::copy_nonoverlapping(ptr.as_ptr(), block.as_ptr(), count) };
+        callers::inherit(block, ptr);
+        unsafe { Self::deallocate_non_null(ptr, layout) };
+        Some(block)
     }
 
-    /// Affiche l'état de chaque arène pour le débogage.
+    /// Retourne le nombre de blocs libres d'une catégorie.
     ///
-    /// Parcourt toutes les arènes et affiche si elles sont initialisées ou non.
+    /// Permet de décider d'accepter un travail (nouvelle connexion, capture)
+    /// sans faire d'allocation d'essai. La valeur peut être périmée dès son
+    /// retour si d'autres contextes allouent en même temps. Les blocs gardés
+    /// dans les magasins (feature `magazines`) ou les réservations (feature
+    /// `reservations`) ne sont pas comptés.
+    ///
+    /// # Returns
+    ///
+    /// Le nombre de blocs libres, ou `0` si l'arène n'est pas initialisée.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{BlockSize, SlabMemory};
+    ///
+    /// assert_eq!(SlabMemory::available(BlockSize::Colossal), 0);
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    ///
+    /// // Chaque arène reçoit 2 Kio : quatre blocs de 512 octets.
+    /// assert_eq!(SlabMemory::available(BlockSize::Colossal), 4);
+    /// if SlabMemory::available(BlockSize::Colossal) < 4 {
+    ///     // Refuser la nouvelle connexion.
+    /// }
+    /// ```
+    pub fn available(block_size: BlockSize) -> usize {
+        #[cfg(not(feature = "lock-free"))]
+        let arena = ARENAS.lock(block_size.index());
+        #[cfg(feature = "lock-free")]
+        let arena = ARENAS.lock_shared(block_size.index());
+        arena.as_ref().map_or(0, Arena::free_count)
+    }
+
+    /// Indique si une allocation de `layout` réussirait.
+    ///
+    /// Suit les mêmes règles que [`SlabMemory::allocate`] sans retirer de
+    /// bloc : [`ZeroSizePolicy`] installée, alignement, échec injecté, plan
+    /// d'allocation, allocateur empoisonné et repli sur la catégorie
+    /// supérieure. Même remarque que [`SlabMemory::available`] : le résultat
+    /// n'est qu'une indication et ne réserve aucun bloc.
+    pub fn can_allocate(layout: Layout) -> bool {
+        let block_size = match Self::classify(layout) {
+            Admission::Dangling => return true,
+            Admission::Refused(_) => return false,
+            Admission::Block(block_size) => block_size,
+        };
+        Self::admit(block_size, layout, false).is_ok()
+            && (Self::available(block_size) > 0
+                || Self::fallback_for(block_size, layout).is_some_and(|larger| Self::available(larger) > 0))
+    }
+
+    /// Termine une allocation : met à jour les statistiques et pose l'en-tête
+    /// du bloc.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_size`: Catégorie dans laquelle le bloc a été demandé.
+    /// - `block`: Bloc retiré de l'arène, ou `None` en cas d'échec.
+    ///
+    /// # Safety
+    ///
+    /// `block` doit être `None` ou un bloc de la catégorie `block_size` qui
+    /// vient d'être alloué.
+    unsafe fn finish_allocation(block_size: BlockSize, block: Option<NonNull<u8>>) -> Option<NonNull<u8>> {
+        let Some(block) = block else {
+            stats::record_failure(block_size.index());
+            trace::record_failure(block_size.index());
+            return None;
+        };
+        stats::record_allocation(block_size.index());
+        let ptr = unsafe { header::attach(block, block_size) };
+        callers::record(ptr);
+        trace::record_allocation(block_size.index(), ptr);
+        Some(ptr)
+    }
+
+    /// Prépare une libération : vérifie l'en-tête du bloc et met à jour les
+    /// statistiques.
+    ///
+    /// Un bloc dont l'en-tête, ou à défaut l'adresse, désigne une autre
+    /// catégorie que `layout` est rendu à l'arène d'origine et compté comme
+    /// incohérent. Un pointeur qui ne désigne aucun bloc (hors de toute arène
+    /// ou à l'intérieur d'un bloc), ou un bloc dont l'en-tête est corrompu,
+    /// n'est jamais remis en liste, afin de ne pas corrompre la liste des
+    /// blocs libres : il est compté et déclenche la [`FatalPolicy`] installée.
+    /// Une fois l'allocateur empoisonné, aucun bloc n'est plus remis en liste.
+    /// Un bloc écarté garde sa place dans le plan d'allocation et dans la
+    /// table des appelants.
+    ///
+    /// # Returns
+    ///
+    /// L'index de l'arène et l'adresse du bloc à y remettre, ou `None` si le
+    /// bloc ne doit pas être remis en liste.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::deallocate`].
+    unsafe fn begin_deallocation(ptr: NonNull<u8>, layout: Layout) -> Option<(usize, NonNull<u8>)> {
+        // Le bloc commence `HEADER_SIZE` octets avant le pointeur retourné.
+        let block_start = ptr.as_ptr().addr().wrapping_sub(HEADER_SIZE);
+        if ARENAS.block_owner(block_start).is_none() {
+            // Les pointeurs des demandes de taille nulle sont hors des arènes.
+            if layout.size() != 0 || ARENAS.owner_of(ptr.as_ptr().addr()).is_some() {
+                stats::record_foreign_free();
+                fatal::corruption("Foreign pointer freed", ptr);
+            }
+            return None;
+        }
+        let block_size = BlockSize::categorize(header::block_request(layout.size()))?;
+        if fatal::is_poisoned() {
+            return None;
+        }
+        let (block, block_size) = match unsafe { header::detach(ptr, block_size) } {
+            Detached::Valid(block, class) => match ARENAS.owner_of(block.as_ptr().addr()) {
+                Some(owner) if owner != class => {
+                    Self::report_layout_mismatch(ptr, owner, layout);
+                    (block, owner)
+                }
+                _ => (block, class),
+            },
+            #[cfg(feature = "block-header")]
+            Detached::Mismatch(block, class) => {
+                Self::report_layout_mismatch(ptr, class, layout);
+                (block, class)
+            }
+            #[cfg(feature = "block-header")]
+            Detached::Corrupt(block) => {
+                stats::record_corrupt_header(block_size.index());
+                fatal::corruption("Corrupt block header", block);
+                return None;
+            }
+        };
+        // Seul un bloc qui retourne en liste quitte le plan et la table des
+        // appelants.
+        allocation_map::release(layout);
+        callers::forget(ptr);
+        stats::record_deallocation(block_size.index());
+        trace::record_deallocation(block_size.index(), ptr);
+        Some((block_size.index(), block))
+    }
+
+    /// Comptabilise la libération d'un pointeur nul.
+    fn report_null_free() {
+        stats::record_null_free();
+        #[cfg(feature = "debug")]
+        let _ = writeln!(DebugWriter, "Null pointer freed");
+    }
+
+    /// Comptabilise la libération d'un bloc de la catégorie `class` avec un
+    /// layout d'une autre catégorie.
+    ///
+    /// Un bloc de la catégorie de repli du layout (voir
+    /// [`SlabMemory::set_fallback`]) n'est pas incohérent.
+    #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+    fn report_layout_mismatch(ptr: NonNull<u8>, class: BlockSize, layout: Layout) {
+        let requested = BlockSize::categorize(header::block_request(layout.size()));
+        if requested.and_then(Self::fallback_of) == Some(class) {
+            return;
+        }
+        stats::record_layout_mismatch(class.index());
+        #[cfg(feature = "debug")]
+        let _ = writeln!(
+            DebugWriter,
+            "Layout mismatch at {:p}: {} byte block freed with size {}",
+            ptr,
+            class.size(),
+            layout.size()
+        );
+    }
+
+    /// Alloue un bloc depuis le magasin du contexte `context`.
+    ///
+    /// Le magasin est rechargé par lots depuis l'arène lorsqu'il est vide : la
+    /// plupart des allocations d'un contexte ne touchent donc pas l'arène
+    /// partagée. Les blocs obtenus peuvent être libérés par
+    /// [`SlabMemory::deallocate_in`] comme par [`SlabMemory::deallocate`].
+    ///
+    /// Un contexte hors de `0..MAGAZINE_CONTEXTS` alloue directement dans l'arène.
+    ///
+    /// Disponible uniquement avec la feature `magazines`.
+    ///
+    /// # Arguments
+    ///
+    /// - `context`: Identifiant du contexte appelant (tâche, niveau d'interruption).
+    /// - `layout`: Spécifie la taille et l'alignement du bloc à allouer.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::allocate`]. Un identifiant de
+    /// contexte ne doit être utilisé que par un seul contexte d'exécution à la
+    /// fois, sans quoi une interruption peut boucler sur le verrou du magasin.
+    #[cfg(feature = "magazines")]
+    pub unsafe fn allocate_in(context: usize, layout: Layout) -> *mut u8 {
+        let Some(block_size) = BlockSize::categorize(header::block_request(layout.size())) else {
+            return core::ptr::null_mut();
+        };
+        if context >= MAGAZINE_CONTEXTS || layout.size() == 0 {
+            return unsafe { Self::allocate(layout) };
+        }
+        if fatal::is_poisoned() {
+            return core::ptr::null_mut();
+        }
+        let _stopwatch = latency::Stopwatch::start(block_size);
+
+        if Self::admit(block_size, layout, true).is_err() {
+            unsafe { Self::finish_allocation(block_size, None) };
+            return core::ptr::null_mut();
+        }
+
+        let index = block_size.index();
+        let mut magazine = MAGAZINES[context][index].lock();
+        let block = magazine.pop().or_else(|| {
+            let count = unsafe { pop_blocks(index, &mut magazine.spare()[..MAGAZINE_SIZE / 2]) };
+            magazine.commit(count);
+            magazine.pop()
+        });
+        if block.is_none() {
+            allocation_map::release(layout);
+        }
+        let block = unsafe { Self::finish_allocation(block_size, block) };
+        block.map_or(core::ptr::null_mut(), NonNull::as_ptr)
+    }
+
+    /// Libère un bloc dans le magasin du contexte `context`.
+    ///
+    /// Lorsque le magasin est plein, la moitié de ses blocs est rendue à
+    /// l'arène en un seul lot.
+    ///
+    /// Un contexte hors de `0..MAGAZINE_CONTEXTS` libère directement dans l'arène.
+    ///
+    /// Disponible uniquement avec la feature `magazines`.
+    ///
+    /// # Arguments
+    ///
+    /// - `context`: Identifiant du contexte appelant.
+    /// - `ptr`: Pointeur vers le bloc à libérer.
+    /// - `layout`: Spécifie la taille et l'alignement du bloc.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::deallocate`] et
+    /// [`SlabMemory::allocate_in`].
+    #[cfg(feature = "magazines")]
+    pub unsafe fn deallocate_in(context: usize, ptr: *mut u8, layout: Layout) {
+        if context >= MAGAZINE_CONTEXTS {
+            unsafe { Self::deallocate(ptr, layout) };
+            return;
+        }
+        let Some(ptr) = NonNull::new(ptr) else {
+            Self::report_null_free();
+            return;
+        };
+        let Some((index, block)) = (unsafe { Self::begin_deallocation(ptr, layout) }) else {
+            return;
+        };
+
+        let mut magazine = MAGAZINES[context][index].lock();
+        if !magazine.push(block) {
+            unsafe { push_blocks(index, magazine.take(MAGAZINE_SIZE / 2)) };
+            magazine.push(block);
+        }
+    }
+
+    /// Rend à leurs arènes tous les blocs en réserve dans les magasins d'un contexte.
+    ///
+    /// À appeler lorsqu'un contexte cesse d'allouer, pour que les blocs gardés
+    /// en réserve redeviennent disponibles pour les autres contextes.
+    ///
+    /// Disponible uniquement avec la feature `magazines`.
+    ///
+    /// # Arguments
+    ///
+    /// - `context`: Identifiant du contexte dont les magasins sont vidés.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::deallocate_in`].
+    #[cfg(feature = "magazines")]
+    pub unsafe fn flush_magazines(context: usize) {
+        if context >= MAGAZINE_CONTEXTS {
+            return;
+        }
+        for (index, magazine) in MAGAZINES[context].iter().enumerate() {
+            let mut magazine = magazine.lock();
+            unsafe { push_blocks(index, magazine.take(MAGAZINE_SIZE)) };
+        }
+    }
+
+    /// Met de côté `count` blocs d'une catégorie sous le nom `name`.
+    ///
+    /// À appeler pendant l'initialisation, avant toute autre allocation, pour
+    /// garantir que des blocs critiques (descripteurs d'une pile réseau, par
+    /// exemple) existeront quoi que fasse le reste du programme. Les blocs
+    /// réservés sont ensuite obtenus via [`SlabMemory::allocate_reserved`] et
+    /// libérés normalement via [`SlabMemory::deallocate`], ce qui les rend à
+    /// l'arène. Ils sont comptés comme alloués dès leur réservation.
+    ///
+    /// Les blocs réservés échappent au plan d'allocation (feature
+    /// `allocation-map`) : une réservation est refusée dans une catégorie où
+    /// le plan installé prévoit des allocations.
+    ///
+    /// Disponible uniquement avec la feature `reservations`.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: Nom de la réservation.
+    /// - `block_size`: Catégorie des blocs à réserver.
+    /// - `count`: Nombre de blocs à réserver.
+    ///
+    /// # Returns
+    ///
+    /// Une [`ReserveError`] si la réservation n'a pas pu être créée ; aucun
+    /// bloc n'est alors retiré de l'arène.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::{BlockSize, SlabMemory};
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    ///
+    /// SlabMemory::reserve("eth-rx", BlockSize::Large, 4).unwrap();
+    /// unsafe {
+    ///     let descriptor = SlabMemory::allocate_reserved("eth-rx");
+    ///     assert!(!descriptor.is_null());
+    ///     SlabMemory::deallocate(descriptor, Layout::from_size_align(48, 8).unwrap());
+    /// }
+    /// ```
+    #[cfg(feature = "reservations")]
+    pub fn reserve(
+        name: &'static str,
+        block_size: BlockSize,
+        count: usize,
+    ) -> Result<(), ReserveError> {
+        let mut reservations = RESERVATIONS.lock();
+        if reservations.iter().flatten().any(|r| r.name() == name) {
+            return Err(ReserveError::DuplicateName);
+        }
+        let slot = reservations
+            .iter_mut()
+            .find(|r| r.is_none())
+            .ok_or(ReserveError::TableFull)?;
+        if !allocation_map::allows_reservation(block_size) {
+            return Err(ReserveError::PlannedClass);
+        }
+
+        let index = block_size.index();
+        let mut reservation = Reservation::new(name, block_size);
+        for _ in 0..count {
+            // Les blocs retirés ne quittent jamais l'allocateur.
+            let Some(block) = (unsafe { pop_block(index) }) else {
+                while let Some(block) = reservation.pop() {
+                    unsafe { push_block(index, block) };
+                }
+                return Err(ReserveError::OutOfMemory);
+            };
+            unsafe { reservation.push(block) };
+        }
+        for _ in 0..count {
+            stats::record_allocation(index);
+        }
+        *slot = Some(reservation);
+        Ok(())
+    }
+
+    /// Alloue un bloc depuis la réservation `name`.
+    ///
+    /// Disponible uniquement avec la feature `reservations`.
+    ///
+    /// # Returns
+    ///
+    /// Un bloc de la catégorie de la réservation, ou `null_mut` si elle
+    /// n'existe pas ou est épuisée.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::allocate`].
+    #[cfg(feature = "reservations")]
+    pub unsafe fn allocate_reserved(name: &str) -> *mut u8 {
+        if fatal::is_poisoned() {
+            return core::ptr::null_mut();
+        }
+        let mut reservations = RESERVATIONS.lock();
+        let Some(reservation) = reservations.iter_mut().flatten().find(|r| r.name() == name) else {
+            return core::ptr::null_mut();
+        };
+        match reservation.pop() {
+            Some(block) => unsafe { header::attach(block, reservation.block_size()) }.as_ptr(),
+            None => core::ptr::null_mut(),
+        }
+    }
+
+    /// Force l'échec des `count` prochaines allocations d'une catégorie.
+    ///
+    /// Permet de tester les chemins de code qui gèrent un manque de mémoire
+    /// sans avoir à épuiser réellement l'arène. Chaque appel remplace le
+    /// compteur précédent de la catégorie ; `count = 0` annule l'injection.
+    ///
+    /// Disponible uniquement avec la feature `fault-injection`.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_size`: Catégorie de bloc dont les allocations doivent échouer.
+    /// - `count`: Nombre d'allocations à faire échouer.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::SlabMemory;
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    ///
+    /// let layout = Layout::from_size_align(32, 8).unwrap();
+    /// unsafe {
+    ///     let ptr = SlabMemory::allocate(layout);
+    ///     let block_size = SlabMemory::arena_of(ptr).unwrap();
+    ///
+    ///     SlabMemory::fail_next(block_size, 1);
+    ///     assert!(SlabMemory::allocate(layout).is_null());
+    ///     assert!(!SlabMemory::allocate(layout).is_null());
+    /// }
+    /// ```
+    #[cfg(feature = "fault-injection")]
+    pub fn fail_next(block_size: BlockSize, count: usize) {
+        FAIL_NEXT[block_size.index()].store(count, Ordering::Relaxed);
+    }
+
+    /// Consomme un échec simulé pour la catégorie donnée, s'il en reste.
+    ///
+    /// Retourne `true` si l'allocation en cours doit échouer.
+    #[cfg(feature = "fault-injection")]
+    fn consume_injected_failure(block_size: BlockSize) -> bool {
+        FAIL_NEXT[block_size.index()]
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    /// Installe un budget de durée des allocations, ou le retire avec `None`.
+    ///
+    /// Chaque allocation est alors mesurée avec le compteur du budget, et sa
+    /// fonction de rappel est appelée lorsqu'une allocation dépasse le budget.
+    ///
+    /// Disponible uniquement avec la feature `latency-budget`.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{BlockSize, LatencyBudget, SlabMemory};
+    ///
+    /// fn cycles() -> u32 {
+    ///     0 // Par exemple `DWT::cycle_count()` sur Cortex-M.
+    /// }
+    ///
+    /// fn overrun(_block_size: BlockSize, _cycles: u32) {
+    ///     // Noter la catégorie et la durée, sans allouer.
+    /// }
+    ///
+    /// SlabMemory::set_latency_budget(Some(LatencyBudget {
+    ///     clock: cycles,
+    ///     budget: 2_000,
+    ///     on_overrun: overrun,
+    /// }));
+    /// ```
+    #[cfg(feature = "latency-budget")]
+    pub fn set_latency_budget(budget: Option<LatencyBudget>) {
+        latency::install(budget);
+    }
+
+    /// Choisit la réaction de l'allocateur à une corruption de ses structures
+    /// internes, comme un en-tête de bloc invalide (feature `block-header`) ou
+    /// la libération d'un pointeur qui ne désigne aucun bloc.
+    ///
+    /// Sans politique installée, le bloc en cause est écarté, compté et
+    /// signalé sur la sortie de débogage, puis l'allocateur continue. Avec
+    /// [`FatalPolicy::Poison`], toute allocation ultérieure échoue et toute
+    /// libération est ignorée, ce qui permet au reste du système de constater
+    /// l'échec et de se mettre en sécurité.
+    ///
+    /// À appeler au démarrage, avant la première allocation.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{FatalPolicy, SlabMemory};
+    ///
+    /// fn reset() -> ! {
+    ///     // Écrire dans le registre de réinitialisation de la cible.
+    ///     loop {}
+    /// }
+    ///
+    /// SlabMemory::set_fatal_policy(FatalPolicy::Reset(reset));
+    /// ```
+    pub fn set_fatal_policy(policy: FatalPolicy) {
+        fatal::install(policy);
+    }
+
+    /// Active ou désactive le repli d'une catégorie sur la catégorie suivante.
+    ///
+    /// Lorsque le repli est activé et que l'arène de `block_size` est vide,
+    /// [`SlabMemory::allocate`] sert la demande depuis l'arène de la catégorie
+    /// immédiatement supérieure : l'allocation réussit au prix d'un bloc plus
+    /// grand que nécessaire. Le bloc est compté comme alloué dans la catégorie
+    /// qui l'a fourni, et le repli dans
+    /// [`ClassStats::fallbacks`](crate::ClassStats) de `block_size`.
+    ///
+    /// Le repli ne concerne que [`SlabMemory::allocate`] et
+    /// [`SlabMemory::allocate_non_null`] ; les allocations par lot, par
+    /// magasin et les réservations n'en bénéficient pas. Il est sans effet
+    /// pour [`BlockSize::Mammoth`]. Les blocs obtenus par repli se libèrent
+    /// normalement, avec le layout de la demande ; tant qu'il en reste,
+    /// désactiver le repli les fait compter comme incohérents à leur
+    /// libération.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{BlockSize, SlabMemory};
+    ///
+    /// // Les descripteurs de 64 octets peuvent déborder sur les 128 octets.
+    /// SlabMemory::set_fallback(BlockSize::Large, true);
+    /// ```
+    pub fn set_fallback(block_size: BlockSize, enabled: bool) {
+        let bit = 1 << block_size.index();
+        if enabled {
+            FALLBACKS.fetch_or(bit, Ordering::Relaxed);
+        } else {
+            FALLBACKS.fetch_and(!bit, Ordering::Relaxed);
+        }
+    }
+
+    /// Choisit l'ordre dans lequel l'arène de `block_size` réutilise ses
+    /// blocs libérés.
+    ///
+    /// [`ReusePolicy::Lifo`] (par défaut) réalloue d'abord le dernier bloc
+    /// libéré, encore dans le cache. [`ReusePolicy::Fifo`] réalloue d'abord
+    /// le plus ancien, pour qu'un bloc libéré reste libre le plus longtemps
+    /// possible : une écriture après libération a plus de chances d'écraser
+    /// le lien d'un bloc encore libre, ce que détecte la vérification de la
+    /// feature `paranoid`, avant que le bloc ne soit réattribué.
+    ///
+    /// Peut être appelée avant ou après l'initialisation ; les blocs déjà
+    /// libres gardent leur ordre. Avec la feature `lock-free`, une arène en
+    /// [`ReusePolicy::Fifo`] est modifiée sous son verrou, comme sans la
+    /// feature.
+    ///
+    /// Le changement se fait sous le verrou exclusif de l'arène, même avec
+    /// `lock-free` : sur un cœur unique, une interruption qui alloue dans
+    /// cette catégorie pendant l'appel ne rend jamais la main.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{BlockSize, ReusePolicy, SlabMemory};
+    ///
+    /// if cfg!(debug_assertions) {
+    ///     SlabMemory::set_reuse_policy(BlockSize::Large, ReusePolicy::Fifo);
+    /// }
+    /// ```
+    pub fn set_reuse_policy(block_size: BlockSize, policy: ReusePolicy) {
+        let bit = 1 << block_size.index();
+        match policy {
+            ReusePolicy::Lifo => FIFO_REUSE.fetch_and(!bit, Ordering::Relaxed),
+            ReusePolicy::Fifo => FIFO_REUSE.fetch_or(bit, Ordering::Relaxed),
+        };
+        if let Some(ref mut arena) = *ARENAS.lock(block_size.index()) {
+            arena.set_reuse_policy(policy);
+        }
+    }
+
+    /// Choisit la réponse aux demandes de taille nulle.
+    ///
+    /// Par défaut ([`ZeroSizePolicy::Dangling`]), une telle demande reçoit un
+    /// pointeur non nul et aligné qui ne consomme aucun bloc, comme l'attendent
+    /// `Box<()>` et les collections vides d'un `Allocator`. Le pointeur n'est
+    /// dans aucune arène : sa libération est ignorée, quelle que soit la
+    /// politique active à ce moment.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::{SlabMemory, ZeroSizePolicy};
+    ///
+    /// let layout = Layout::from_size_align(0, 16).unwrap();
+    /// let ptr = unsafe { SlabMemory::allocate(layout) };
+    /// assert_eq!(ptr.addr(), 16);
+    /// unsafe { SlabMemory::deallocate(ptr, layout) };
+    ///
+    /// SlabMemory::set_zero_size_policy(ZeroSizePolicy::Null);
+    /// assert!(unsafe { SlabMemory::allocate(layout) }.is_null());
+    /// # SlabMemory::set_zero_size_policy(ZeroSizePolicy::Dangling);
+    /// ```
+    pub fn set_zero_size_policy(policy: ZeroSizePolicy) {
+        ZERO_SIZE.store(policy as u8, Ordering::Relaxed);
+    }
+
+    /// Retourne la politique appliquée aux demandes de taille nulle.
+    fn zero_size_policy() -> ZeroSizePolicy {
+        ZeroSizePolicy::from_u8(ZERO_SIZE.load(Ordering::Relaxed))
+    }
+
+    /// Installe ou retire le fournisseur d'identifiant d'appelant.
+    ///
+    /// À chaque allocation réussie, `provider` est appelé et son résultat est
+    /// enregistré avec le bloc jusqu'à sa libération. L'identifiant est libre :
+    /// adresse de retour lue par une macro de l'application, numéro de tâche
+    /// ou chemin de code courant. Les rapports de fuite
+    /// ([`SlabMemory::write_leak_report`]) indiquent alors d'où viennent les
+    /// blocs restés alloués.
+    ///
+    /// Au plus [`MAX_TRACKED_CALLERS`](crate::MAX_TRACKED_CALLERS) blocs
+    /// vivants sont enregistrés ; au-delà, la politique installée par
+    /// [`SlabMemory::set_tracking_overflow`] s'applique. Les blocs des
+    /// réservations ne sont pas enregistrés.
+    ///
+    /// Disponible uniquement avec la feature `caller-tags`.
+    ///
+    /// # Arguments
+    ///
+    /// - `provider`: Fonction appelée dans le contexte de l'allocation. Elle ne
+    ///   doit pas allouer. `None` arrête l'enregistrement.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::sync::atomic::{AtomicUsize, Ordering};
+    /// use global_allocator::SlabMemory;
+    ///
+    /// /// Chemin de code courant, mis à jour par l'application.
+    /// static CURRENT_PATH: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// fn current_path() -> usize {
+    ///     CURRENT_PATH.load(Ordering::Relaxed)
+    /// }
+    ///
+    /// SlabMemory::set_caller_provider(Some(current_path));
+    /// ```
+    #[cfg(feature = "caller-tags")]
+    pub fn set_caller_provider(provider: Option<fn() -> usize>) {
+        callers::install(provider);
+    }
+
+    /// Choisit la réaction de la table des appelants lorsqu'elle est pleine,
+    /// et réactive l'enregistrement s'il avait été arrêté par
+    /// [`TrackingOverflow::Disable`].
+    ///
+    /// Par défaut ([`TrackingOverflow::DropNewest`]), une allocation qui ne
+    /// trouve pas de place n'est pas enregistrée. Sur une petite cible où la
+    /// table est réduite (voir [`MAX_TRACKED_CALLERS`](crate::MAX_TRACKED_CALLERS)),
+    /// [`TrackingOverflow::Disable`] évite de parcourir une table pleine à
+    /// chaque allocation.
+    ///
+    /// Disponible uniquement avec la feature `caller-tags`.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{SlabMemory, TrackingOverflow};
+    ///
+    /// SlabMemory::set_tracking_overflow(TrackingOverflow::DropOldest);
+    /// ```
+    #[cfg(feature = "caller-tags")]
+    pub fn set_tracking_overflow(policy: TrackingOverflow) {
+        callers::set_overflow(policy);
+    }
+
+    /// Parcourt les blocs vivants dont l'appelant est enregistré.
+    ///
+    /// Disponible uniquement avec la feature `caller-tags`.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: Fonction appelée pour chaque bloc enregistré.
+    #[cfg(feature = "caller-tags")]
+    pub fn live_blocks(mut f: impl FnMut(LiveBlock)) {
+        callers::for_each(|_, address, caller| {
+            let block_size = ARENAS.owner_of(address);
+            if let (Some(block_size), Some(ptr)) = (block_size, NonNull::new(address as *mut u8)) {
+                f(LiveBlock { ptr, block_size, caller });
+            }
+        });
+    }
+
+    /// Écrit un rapport des blocs vivants regroupés par appelant.
+    ///
+    /// Chaque ligne donne un identifiant d'appelant, une catégorie et le
+    /// nombre de blocs de cette catégorie qu'il a alloués et pas encore
+    /// libérés :
+    ///
+    /// ```text
+    /// caller 0x2: 3 live 32-byte blocks
+    /// caller 0x5: 1 live 256-byte blocks
+    /// untracked: 4 allocations
+    /// ```
+    ///
+    /// La dernière ligne n'apparaît que si des allocations n'ont pas pu être
+    /// enregistrées ; c'est un total cumulé depuis le démarrage, qui compte
+    /// aussi celles déjà libérées, car leur libération ne peut pas être
+    /// reconnue. À appeler à un point où le système ne devrait plus rien
+    /// détenir (fin de test, retour à l'état de repos) : les blocs restants sont
+    /// des fuites. Aucune allocation n'est effectuée pendant l'écriture.
+    ///
+    /// Disponible uniquement avec la feature `caller-tags`.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer`: Destination du rapport.
+    #[cfg(feature = "caller-tags")]
+    pub fn write_leak_report(writer: &mut impl core::fmt::Write) -> core::fmt::Result {
+        callers::write_report(writer, |address| ARENAS.owner_of(address))
+    }
+
+    /// Installe le plan d'allocation déclaré par [`allocation_map!`](crate::allocation_map).
+    ///
+    /// Une fois le plan installé, toute allocation dont la taille n'y figure
+    /// pas, ou qui dépasserait le nombre d'allocations simultanées déclaré pour
+    /// sa taille, échoue : elle est comptée (voir
+    /// [`SlabMemory::allocation_map_violations`]) et signalée sur la sortie de
+    /// débogage. Le plan est vérifié contre la capacité des arènes, de sorte
+    /// qu'aucune allocation prévue ne peut manquer de mémoire.
+    ///
+    /// Le plan doit être installé avant toute allocation ou réservation : un
+    /// bloc remis avant lui n'y serait pas compté, et sa libération rendrait
+    /// au plan une place qu'il n'a jamais accordée. Les réservations (feature
+    /// `reservations`) et les [`FixedPool`](crate::FixedPool) ne sont pas
+    /// soumis au plan ; une réservation n'est donc acceptée que dans une
+    /// catégorie sans taille du plan.
+    ///
+    /// Disponible uniquement avec la feature `allocation-map`.
+    ///
+    /// # Arguments
+    ///
+    /// - `entries`: Plan déclaré par [`allocation_map!`](crate::allocation_map).
+    ///
+    /// # Returns
+    ///
+    /// Une [`AllocationMapError`] si un plan est déjà installé, si un bloc a
+    /// déjà été alloué ou réservé, ou si une arène est trop petite pour le
+    /// plan. À appeler après [`SlabMemory::initialize`].
+    ///
+    /// Toutes les arènes sont verrouillées pendant la vérification, y compris
+    /// avec la feature `lock-free` : à appeler au démarrage, avant que des
+    /// interruptions ne puissent allouer.
+    #[cfg(feature = "allocation-map")]
+    pub fn set_allocation_map(
+        entries: &'static [AllocationEntry],
+    ) -> Result<(), AllocationMapError> {
+        let arenas = ARENAS.lock_all();
+        let mut needed = [0usize; 8];
+        for entry in entries {
+            if let Some(block_size) = BlockSize::categorize(header::block_request(entry.size())) {
+                needed[block_size.index()] += entry.count();
+            }
+        }
+        for (block_size, arena) in BlockSize::iter().zip(&arenas) {
+            if needed[block_size.index()] > arena.as_ref().map_or(0, Arena::block_count) {
+                return Err(AllocationMapError::ExceedsArena(block_size));
+            }
+        }
+        drop(arenas);
+        allocation_map::install(entries)
+    }
+
+    /// Retourne le nombre d'allocations refusées car hors du plan d'allocation.
+    ///
+    /// Disponible uniquement avec la feature `allocation-map`.
+    #[cfg(feature = "allocation-map")]
+    pub fn allocation_map_violations() -> usize {
+        allocation_map::violations()
+    }
+
+    /// Retourne le nombre de libérations d'une taille du plan alors qu'aucune
+    /// allocation de cette taille n'y était comptée, ce qui signale un bloc
+    /// libéré avec un autre layout que celui de son allocation.
+    ///
+    /// Disponible uniquement avec la feature `allocation-map`.
+    #[cfg(feature = "allocation-map")]
+    pub fn allocation_map_unmatched_releases() -> usize {
+        allocation_map::unmatched_releases()
+    }
+
+    /// Retourne le plus grand alignement demandé dans une catégorie.
+    ///
+    /// Disponible uniquement avec la feature `alignment-audit`.
+    #[cfg(feature = "alignment-audit")]
+    pub fn max_alignment(block_size: BlockSize) -> usize {
+        alignment::max_alignment(block_size.index())
+    }
+
+    /// Retourne le nombre d'allocations d'une catégorie refusées parce que
+    /// l'alignement demandé dépassait celui que ses pointeurs garantissent.
+    ///
+    /// Chacune est aussi signalée sur la sortie de débogage (feature `debug`)
+    /// avec sa taille et son alignement, pour retrouver le code appelant.
+    ///
+    /// Disponible uniquement avec la feature `alignment-audit`.
+    #[cfg(feature = "alignment-audit")]
+    pub fn over_aligned_allocations(block_size: BlockSize) -> usize {
+        alignment::over_aligned(block_size.index())
+    }
+
+    /// Initialise les arènes avec un espace mémoire donné.
+    ///
+    /// Le tas est réparti également entre les catégories ; voir
+    /// [`SlabMemory::initialize_with`] pour une autre répartition.
+    ///
+    /// # Arguments
+    ///
+    /// - `heap_start`: Adresse de début de la mémoire gérée.
+    /// - `heap_size`: Taille totale de la mémoire.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::initialize_with`].
+    pub unsafe fn initialize(heap_start: *mut u8, heap_size: usize) {
+        unsafe { Self::initialize_with(heap_start, heap_size, ArenaConfig::default()) };
+    }
+
+    /// Initialise les arènes en répartissant le tas selon `config`.
+    ///
+    /// La part de chaque arène est arrondie à un multiple de sa taille de bloc.
+    /// Les arènes sont placées de la plus grande taille de bloc à la plus
+    /// petite : si `heap_start` est aligné sur [`BlockSize::MAX`], chaque bloc
+    /// est aligné sur sa propre taille, et le pointeur remis aussi sans la
+    /// feature `block-header` (voir [`SlabMemory::MIN_ALIGN`]). Sinon,
+    /// `heap_start` est avancé jusqu'au prochain multiple de
+    /// [`SlabMemory::MIN_ALIGN`].
+    ///
+    /// # Arguments
+    ///
+    /// - `heap_start`: Adresse de début de la mémoire gérée.
+    /// - `heap_size`: Taille totale de la mémoire.
+    /// - `config`: Répartition du tas entre les catégories.
+    ///
+    /// # Safety
+    ///
+    /// L'appelant doit s'assurer que `heap_start` pointe vers une zone de
+    /// mémoire valide et accessible en lecture et écriture sur `heap_size`
+    /// octets, réservée à l'allocateur. Une zone invalide, ou un appel alors
+    /// que le tas est déjà initialisé (voir [`SlabMemory::try_initialize`]),
+    /// est ignoré.
+    pub unsafe fn initialize_with(heap_start: *mut u8, heap_size: usize, config: ArenaConfig) {
+        let _ = unsafe { Self::try_initialize(heap_start, heap_size, config, &[]) };
+    }
+
+    /// Initialise les arènes comme [`SlabMemory::initialize_with`], après
+    /// avoir vérifié la zone du tas.
+    ///
+    /// La zone ne doit pas être nulle, ne doit pas dépasser la fin de l'espace
+    /// d'adressage et ne doit chevaucher aucune des plages de `reserved` :
+    /// typiquement la pile et sa garde, qu'une erreur de script d'édition de
+    /// liens placerait sinon sous les arènes. La répartition `config` ne doit
+    /// pas déborder ([`RegionError::ConfigOverflow`]). Le tas ne s'initialise
+    /// qu'une fois : un nouvel appel retourne
+    /// [`RegionError::AlreadyInitialized`], car les blocs déjà remis, les
+    /// magasins, les réservations et les compteurs désignent l'ancien tas. En
+    /// cas d'erreur, aucune arène n'est modifiée.
+    ///
+    /// Une arène gère au plus `2^(usize::BITS / 2) - 1` blocs, soit 65 535 sur
+    /// une cible 32 bits : au-delà, sa part du tas reste inutilisée, ce qui
+    /// est signalé sur la sortie de débogage (feature `debug`). Une
+    /// répartition `config` qui en tient compte évite ce gaspillage.
+    ///
+    /// Les arènes sont construites sous leurs verrous exclusifs, même avec la
+    /// feature `lock-free` : l'initialisation précède l'activation des
+    /// interruptions qui allouent.
+    ///
+    /// # Arguments
+    ///
+    /// - `heap_start`: Adresse de début de la mémoire gérée.
+    /// - `heap_size`: Taille totale de la mémoire.
+    /// - `config`: Répartition du tas entre les catégories.
+    /// - `reserved`: Plages d'adresses que le tas ne doit pas chevaucher.
+    ///
+    /// # Returns
+    ///
+    /// Une [`RegionError`] décrivant la première vérification échouée.
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`SlabMemory::initialize_with`] pour une zone
+    /// valide.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::{ArenaConfig, RegionError, SlabMemory};
+    ///
+    /// let heap: &'static mut [u8] = Box::leak(vec![0u8; 16 * 1024].into_boxed_slice());
+    /// let start = heap.as_ptr().addr();
+    /// // Pile placée par erreur au milieu du tas.
+    /// let stack = start + 4096..start + 8192;
+    ///
+    /// let result = unsafe {
+    ///     SlabMemory::try_initialize(heap.as_mut_ptr(), heap.len(), ArenaConfig::default(), &[stack])
+    /// };
+    /// assert_eq!(result, Err(RegionError::Overlaps(0)));
+    ///
+    /// let result = unsafe {
+    ///     SlabMemory::try_initialize(heap.as_mut_ptr(), heap.len(), ArenaConfig::default(), &[])
+    /// };
+    /// assert_eq!(result, Ok(()));
+    /// // Le tas ne se reconstruit pas sous des blocs peut-être vivants.
+    /// let result = unsafe {
+    ///     SlabMemory::try_initialize(heap.as_mut_ptr(), heap.len(), ArenaConfig::default(), &[])
+    /// };
+    /// assert_eq!(result, Err(RegionError::AlreadyInitialized));
+    /// ```
+    pub unsafe fn try_initialize(
+        heap_start: *mut u8,
+        heap_size: usize,
+        config: ArenaConfig,
+        reserved: &[Range<usize>],
+    ) -> Result<(), RegionError> {
+        let start = NonNull::new(heap_start).ok_or(RegionError::Null)?;
+        let skip = align_to(start.as_ptr().addr(), Self::MIN_ALIGN) - start.as_ptr().addr();
+        let heap_size = heap_size.checked_sub(skip).ok_or(RegionError::TooSmall)?;
+        let mut current = unsafe { start.byte_add(skip) };
+        region::validate(current.as_ptr().addr(), heap_size, reserved)?;
+        let mut capacities = [0; BlockSize::COUNT];
+        for (i, capacity) in capacities.iter_mut().enumerate() {
+            *capacity = config.arena_bytes(i, heap_size).ok_or(RegionError::ConfigOverflow)?;
+        }
+        let mut arenas = ARENAS.lock_all();
+        if ARENAS.is_initialized() {
+            return Err(RegionError::AlreadyInitialized);
+        }
+
+        for i in (0..arenas.len()).rev() {
+            let capacity = capacities[i];
+            #[cfg(feature = "debug")]
+            if capacity / BlockSize::ALL[i].size() > INDEX_MASK {
+                let _ = writeln!(
+                    DebugWriter,
+                    "Arena {}: {} bytes beyond the {}-block limit left unused",
+                    BlockSize::ALL[i].size(),
+                    capacity - INDEX_MASK * BlockSize::ALL[i].size(),
+                    INDEX_MASK
+                );
+            }
+            unsafe { ARENAS.install(&mut arenas[i], i, current, capacity) };
+            if FIFO_REUSE.load(Ordering::Relaxed) & (1 << i) != 0 {
+                if let Some(ref mut arena) = *arenas[i] {
+                    arena.set_reuse_policy(ReusePolicy::Fifo);
+                }
+            }
+            current = unsafe { current.byte_add(capacity) };
+            #[cfg(feature = "gdb-symbols")]
+            symbols::publish(i, arenas[i].as_ref());
+        }
+        Ok(())
+    }
+
+    /// Retourne la catégorie de l'arène qui contient `ptr`.
+    ///
+    /// Ne prend aucun verrou et ne dépend pas du nombre d'allocations : permet
+    /// de retrouver la catégorie d'un pointeur sans connaître son layout, ou
+    /// de reconnaître un pointeur étranger au tas.
+    ///
+    /// # Returns
+    ///
+    /// La catégorie de l'arène contenant l'adresse de `ptr`, ou `None` si elle
+    /// est hors du tas ou si le tas n'est pas initialisé.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::{BlockSize, SlabMemory};
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    ///
+    /// let layout = Layout::from_size_align(100, 8).unwrap();
+    /// let ptr = unsafe { SlabMemory::allocate(layout) };
+    /// assert_eq!(SlabMemory::arena_of(ptr), BlockSize::categorize(100));
+    /// assert_eq!(SlabMemory::arena_of(&layout as *const _ as *const u8), None);
+    /// unsafe { SlabMemory::deallocate(ptr, layout) };
+    /// ```
+    pub fn arena_of(ptr: *const u8) -> Option<BlockSize> {
+        ARENAS.owner_of(ptr.addr())
+    }
+
+    /// Indique si [`SlabMemory::initialize`] a déjà préparé les arènes.
+    ///
+    /// Tant que ce n'est pas le cas, toute allocation échoue.
+    pub fn is_initialized() -> bool {
+        ARENAS.is_initialized()
+    }
+
+    /// Retourne les statistiques d'utilisation de chaque catégorie.
+    ///
+    /// Disponible uniquement avec la feature `stats` (activée par défaut).
+    ///
+    /// Les blocs gardés en réserve dans les magasins (feature `magazines`) ne
+    /// sont comptés ni comme alloués ni comme libres.
+    ///
+    /// La lecture se fait sous le verrou de toutes les arènes, même avec la
+    /// feature `lock-free`. Sur un cœur unique, une interruption qui alloue
+    /// pendant l'appel y attend indéfiniment : l'appeler avec ces
+    /// interruptions masquées.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use core::alloc::Layout;
+    /// use global_allocator::SlabMemory;
+    ///
+    /// #[repr(align(1024))]
+    /// struct Heap([u8; 16 * 1024]);
+    ///
+    /// let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+    /// unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    /// assert_eq!(SlabMemory::stats().in_use_bytes(), 0);
+    ///
+    /// let layout = Layout::from_size_align(100, 8).unwrap();
+    /// let ptr = unsafe { SlabMemory::allocate(layout) };
+    /// let block_size = SlabMemory::arena_of(ptr).unwrap();
+    ///
+    /// let stats = SlabMemory::stats();
+    /// assert_eq!(stats.classes[block_size.index()].in_use, 1);
+    /// assert_eq!(stats.in_use_bytes(), block_size.size());
+    /// unsafe { SlabMemory::deallocate(ptr, layout) };
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn stats() -> MemoryStats {
+        let arenas = ARENAS.lock_all();
+        let (null_frees, foreign_frees) = stats::ignored_frees();
+        MemoryStats {
+            classes: core::array::from_fn(|i| {
+                stats::snapshot(i, arenas[i].as_ref().map_or(0, Arena::block_count))
+            }),
+            null_frees,
+            foreign_frees,
+        }
+    }
+
+    /// Parcourt les blocs libres de l'arène d'une catégorie.
+    ///
+    /// Appelle `f` avec un itérateur sur les adresses des blocs libres, en
+    /// tenant le verrou de l'arène pendant tout le parcours. Destiné aux outils
+    /// de diagnostic (auto-test, visualisation du tas). Ce verrou est exclusif
+    /// même avec la feature `lock-free` : une interruption qui alloue dans
+    /// cette catégorie pendant le parcours bloque un cœur unique.
+    ///
+    /// Disponible uniquement avec la feature `debug`.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_size`: Catégorie de l'arène à parcourir.
+    /// - `f`: Fonction recevant l'itérateur. Elle ne doit pas allouer dans
+    ///   cette catégorie, sous peine d'interblocage.
+    ///
+    /// # Returns
+    ///
+    /// Le résultat de `f`, ou `None` si l'arène n'est pas initialisée.
+    #[cfg(feature = "debug")]
+    pub fn free_blocks<R>(block_size: BlockSize, f: impl FnOnce(FreeBlocks<'_>) -> R) -> Option<R> {
+        ARENAS.lock(block_size.index()).as_ref().map(|arena| f(arena.free_blocks()))
+    }
+
+    /// Écrit une image binaire compacte de l'état du tas dans `sink`.
+    ///
+    /// L'image décrit la disposition de chaque arène et une bitmap des blocs
+    /// alloués, pour que des outils côté hôte puissent afficher une carte du
+    /// tas. Aucune allocation n'est effectuée pendant l'écriture.
+    ///
+    /// Disponible uniquement avec la feature `debug`. Le décodeur
+    /// [`dump::decode`](crate::dump) est fourni avec la feature `std`.
+    ///
+    /// # Format
+    ///
+    /// Tous les entiers sont en little-endian.
+    ///
+    /// | Offset | Taille | Contenu                                  |
+    /// |--------|--------|------------------------------------------|
+    /// | 0      | 4      | Signature `"SLAB"`                       |
+    /// | 4      | 1      | Version du format (`1`)                  |
+    /// | 5      | 1      | Nombre `N` d'enregistrements d'arène     |
+    ///
+    /// Suivi de `N` enregistrements, par taille de bloc croissante :
+    ///
+    /// | Offset | Taille    | Contenu                                     |
+    /// |--------|-----------|---------------------------------------------|
+    /// | 0      | 4         | Taille de bloc en octets (`u32`)            |
+    /// | 4      | 4         | Nombre de blocs `B` (`u32`, `0` si absente) |
+    /// | 8      | 8         | Adresse de début de l'arène (`u64`)         |
+    /// | 16     | ⌈`B`/8⌉   | Bitmap : bit `i` à `1` si le bloc `i` est alloué (poids faible d'abord) |
+    ///
+    /// Toutes les arènes restent verrouillées pendant l'écriture, ce qui donne
+    /// une image cohérente ; `sink` ne doit donc pas allouer. Ces verrous sont
+    /// exclusifs même avec la feature `lock-free` : sur un cœur unique, les
+    /// interruptions qui allouent doivent être masquées pendant l'écriture.
+    ///
+    /// # Arguments
+    ///
+    /// - `sink`: Destination des octets de l'image.
+    #[cfg(feature = "debug")]
+    pub fn dump<S: DumpSink>(sink: &mut S) {
+        let arenas = ARENAS.lock_all();
+
+        dump::write_header(sink, arenas.len() as u8);
+        for (block_size, arena) in BlockSize::iter().zip(&arenas) {
+            dump::write_arena(sink, block_size.size(), arena.as_ref());
+        }
+    }
+
+    /// Écrit un rapport JSON compact des statistiques du tas dans `sink`.
+    ///
+    /// Destiné aux outils côté hôte (banc de test de production) qui lisent la
+    /// sortie d'auto-test de la cible avec un analyseur JSON standard. Aucune
+    /// allocation n'est effectuée pendant l'écriture.
+    ///
+    /// Disponible uniquement avec les features `debug` et `stats`.
+    ///
+    /// # Format
+    ///
+    /// Un seul objet, sans espaces, dont les champs reprennent
+    /// [`MemoryStats`] et [`ClassStats`](crate::ClassStats) :
+    ///
+    /// ```text
+    /// {"version":1,"in_use_bytes":96,"overhead_bytes":0,"null_frees":0,
+    ///  "foreign_frees":0,"classes":[
+    ///   {"block_size":8,"capacity":128,"in_use":2,"allocations":5,
+    ///    "deallocations":3,"failures":0,"overhead_bytes":0,
+    ///    "layout_mismatches":0,"corrupt_headers":0,"fallbacks":0}, ...]}
+    /// ```
+    ///
+    /// `classes` contient une entrée par catégorie, par taille de bloc croissante.
+    ///
+    /// # Arguments
+    ///
+    /// - `sink`: Destination des octets du rapport.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::SlabMemory;
+    ///
+    /// let mut report = Vec::new();
+    /// SlabMemory::report_json(&mut |bytes: &[u8]| report.extend_from_slice(bytes));
+    /// assert!(report.starts_with(b"{\"version\":1,"));
+    /// ```
+    #[cfg(all(feature = "debug", feature = "stats"))]
+    pub fn report_json<S: DumpSink>(sink: &mut S) {
+        report::write_json(sink, &Self::stats());
+    }
+
+    /// Écrit les statistiques du tas sous forme de métriques texte, une par
+    /// ligne, au format `nom{étiquette} valeur` de Prometheus.
+    ///
+    /// Destiné aux passerelles qui transmettent telles quelles les métriques
+    /// de la cible à un serveur de supervision. Aucune allocation n'est
+    /// effectuée pendant l'écriture.
+    ///
+    /// Disponible uniquement avec la feature `metrics`.
+    ///
+    /// # Format
+    ///
+    /// Les métriques globales reprennent [`MemoryStats`], puis chaque champ de
+    /// [`ClassStats`](crate::ClassStats) donne une métrique étiquetée par
+    /// taille de bloc, avec une ligne par catégorie. Les compteurs cumulés
+    /// portent le suffixe `_total` :
+    ///
+    /// ```text
+    /// slab_in_use_bytes 96
+    /// slab_overhead_bytes 0
+    /// slab_null_frees_total 0
+    /// slab_foreign_frees_total 0
+    /// slab_class_capacity_blocks{block_size="8"} 128
+    /// slab_class_capacity_blocks{block_size="16"} 64
+    /// ...
+    /// slab_class_fallbacks_total{block_size="1024"} 0
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `writer`: Destination des lignes.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::SlabMemory;
+    ///
+    /// let mut metrics = String::new();
+    /// SlabMemory::export_metrics(&mut metrics).unwrap();
+    /// assert!(metrics.starts_with("slab_in_use_bytes "));
+    /// assert!(metrics.contains("slab_class_in_use_blocks{block_size=\"1024\"} "));
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn export_metrics(writer: &mut impl core::fmt::Write) -> core::fmt::Result {
+        metrics::write_metrics(writer, &Self::stats())
+    }
+
+    /// Installe la fonction qui reçoit la sortie de débogage, octet par octet.
+    ///
+    /// Tous les messages de l'allocateur (corruptions, libérations étrangères,
+    /// plan d'allocation, [`SlabMemory::debug_print`], gestionnaire de panique
+    /// de la feature `panic-handler`) passent par elle ; sans fonction
+    /// installée, ils sont ignorés.
+    ///
+    /// Disponible uniquement avec la feature `debug`.
+    ///
+    /// # Arguments
+    ///
+    /// - `sink`: Fonction écrivant un octet, par exemple dans le registre de
+    ///   données d'un UART. Elle peut être appelée depuis n'importe quel
+    ///   contexte, y compris une panique, et ne doit pas allouer. `None` coupe
+    ///   la sortie.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::SlabMemory;
+    ///
+    /// fn uart_write(_byte: u8) {
+    ///     // Écrire dans le registre de données de l'UART.
+    /// }
+    ///
+    /// SlabMemory::set_debug_sink(Some(uart_write));
+    /// ```
+    #[cfg(feature = "debug")]
+    pub fn set_debug_sink(sink: Option<fn(u8)>) {
+        debug::install(sink);
+    }
+
+    /// Affiche l'état de chaque arène pour le débogage.
+    ///
+    /// Parcourt toutes les arènes et affiche si elles sont initialisées ou non.
+    /// Les arènes restent verrouillées, même avec la feature `lock-free`,
+    /// jusqu'à la fin de l'affichage.
+    ///
+    /// Disponible uniquement avec la feature `debug`.
     ///
     /// # Safety
     ///
-    /// Cette méthode suppose que les arènes ont été correctement initialisées
-    /// via [`SlabMemory::initialize`].
+    /// La sortie de débogage doit être prête à recevoir des données.
+    #[cfg(feature = "debug")]
     pub unsafe fn debug_print() {
-        let arenas = ARENAS.assume_init_mut();
+        let arenas = ARENAS.lock_all();
         let mut writer = DebugWriter;
 
         for (i, arena) in arenas.iter().enumerate() {
             if arena.is_some() {
-                let _ = write!(writer, "Arena {}: Initialized\n", i);
+                let _ = writeln!(writer, "Arena {}: Initialized", i);
             } else {
-                let _ = write!(writer, "Arena {}: Not initialized\n", i);
+                let _ = writeln!(writer, "Arena {}: Not initialized", i);
             }
         }
     }
@@ -0,0 +1,38 @@
+use core::fmt::{self, Write};
+use crate::stats::{ClassStats, MemoryStats};
+
+/// Lecture d'un champ de [`ClassStats`].
+type ClassField = fn(&ClassStats) -> usize;
+
+/// Métriques par catégorie : nom et champ de [`ClassStats`] correspondant.
+///
+/// Les compteurs cumulés portent le suffixe `_total`, à la manière de
+/// Prometheus.
+const CLASS_METRICS: [(&str, ClassField); 9] = [
+    ("slab_class_capacity_blocks", |class| class.capacity),
+    ("slab_class_in_use_blocks", |class| class.in_use),
+    ("slab_class_allocations_total", |class| class.allocations),
+    ("slab_class_deallocations_total", |class| class.deallocations),
+    ("slab_class_failures_total", |class| class.failures),
+    ("slab_class_overhead_bytes", |class| class.overhead_bytes),
+    ("slab_class_layout_mismatches_total", |class| class.layout_mismatches),
+    ("slab_class_corrupt_headers_total", |class| class.corrupt_headers),
+    ("slab_class_fallbacks_total", |class| class.fallbacks),
+];
+
+/// Écrit les métriques de `stats` dans `writer`, une par ligne.
+///
+/// Les échantillons d'une même métrique sont consécutifs, comme l'exige le
+/// format texte de Prometheus.
+pub(crate) fn write_metrics(writer: &mut impl Write, stats: &MemoryStats) -> fmt::Result {
+    writeln!(writer, "slab_in_use_bytes {}", stats.in_use_bytes())?;
+    writeln!(writer, "slab_overhead_bytes {}", stats.overhead_bytes())?;
+    writeln!(writer, "slab_null_frees_total {}", stats.null_frees)?;
+    writeln!(writer, "slab_foreign_frees_total {}", stats.foreign_frees)?;
+    for (name, value) in CLASS_METRICS {
+        for class in &stats.classes {
+            writeln!(writer, "{}{{block_size=\"{}\"}} {}", name, class.block_size, value(class))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use core::fmt::Write;
+use core::panic::PanicInfo;
+use core::sync::atomic::{AtomicBool, Ordering};
+use crate::config::BlockSize;
+use crate::debug::DebugWriter;
+use crate::fatal;
+use crate::stats;
+use crate::trace;
+
+/// Indique qu'une panique est déjà en cours de traitement.
+///
+/// Une panique pendant l'affichage du rapport passe directement à la
+/// réinitialisation.
+static PANICKING: AtomicBool = AtomicBool::new(false);
+
+/// Gestionnaire de panique : écrit le message, un résumé du tas et les
+/// derniers événements d'allocation sur la sortie de débogage, puis
+/// réinitialise la cible.
+///
+/// Le résumé ne lit que les compteurs de statistiques et l'anneau des
+/// événements, sans prendre de verrou : il reste disponible si la panique
+/// survient pendant qu'une arène est verrouillée.
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    if !PANICKING.swap(true, Ordering::SeqCst) {
+        let mut writer = DebugWriter;
+        let _ = writeln!(writer, "panic: {}", info);
+        write_heap_summary(&mut writer);
+        trace::write_recent(&mut writer);
+    }
+    reset()
+}
+
+/// Écrit une ligne résumant l'occupation du tas.
+fn write_heap_summary(writer: &mut DebugWriter) {
+    let (mut blocks, mut bytes, mut failures) = (0, 0, 0);
+    for index in 0..BlockSize::COUNT {
+        let class = stats::snapshot(index, 0);
+        blocks += class.in_use;
+        bytes += class.in_use * class.block_size;
+        failures += class.failures;
+    }
+    let _ = writeln!(
+        writer,
+        "heap: {} blocks in use ({} bytes), {} failed allocations",
+        blocks, bytes, failures
+    );
+}
+
+/// Réinitialise la cible après une panique.
+///
+/// Appelle la fonction installée par [`FatalPolicy::Reset`](crate::FatalPolicy::Reset).
+/// Sans elle, boucle indéfiniment, comme [`FatalPolicy::Halt`](crate::FatalPolicy::Halt),
+/// pour qu'un débogueur puisse examiner la cible.
+fn reset() -> ! {
+    if let Some(reset) = fatal::reset_hook() {
+        reset()
+    }
+    loop {
+        core::hint::spin_loop();
+    }
+}
@@ -0,0 +1,138 @@
+use core::mem;
+use core::ptr::NonNull;
+use crate::arena::Arena;
+use crate::utils::align_to;
+
+/// Un pool de blocs de taille fixe, utilisable sans l'allocateur global.
+///
+/// Façade sûre au-dessus d'une arène (`Arena`) : la mémoire est fournie une fois
+/// pour toutes sous forme de `&'static mut [u8]`, et chaque bloc alloué est
+/// représenté par un [`PoolBlock`] qui doit être rendu via [`FixedPool::free`].
+///
+/// # Exemple
+///
+/// ```rust
+/// use global_allocator::FixedPool;
+///
+/// let memory: &'static mut [u8] = Box::leak(Box::new([0u8; 1024]));
+/// let mut pool = FixedPool::new(memory, 64).unwrap();
+///
+/// let mut frame = pool.try_alloc().unwrap();
+/// frame.as_mut_slice()[0] = 0x7E;
+/// pool.free(frame).unwrap();
+/// ```
+pub struct FixedPool {
+    /// Arène gérant les blocs du pool.
+    arena: Arena,
+}
+
+/// Un bloc alloué depuis un [`FixedPool`].
+///
+/// Le bloc appartient exclusivement à son détenteur jusqu'à ce qu'il soit
+/// rendu au pool. Un `PoolBlock` détruit sans être rendu est perdu pour le pool.
+#[derive(Debug)]
+pub struct PoolBlock {
+    /// Début du bloc.
+    ptr: NonNull<u8>,
+    /// Taille du bloc, en octets.
+    len: usize,
+}
+
+// Le bloc est une zone mémoire détenue exclusivement.
+unsafe impl Send for PoolBlock {}
+
+impl PoolBlock {
+    /// Retourne le contenu du bloc.
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Retourne le contenu du bloc, modifiable.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Retourne un pointeur vers le début du bloc.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+}
+
+impl FixedPool {
+    /// Crée un pool de blocs de `block_size` octets dans `memory`.
+    ///
+    /// Le début de `memory` est ignoré si nécessaire pour aligner les blocs sur
+    /// la taille d'un pointeur.
+    ///
+    /// # Arguments
+    ///
+    /// - `memory`: Mémoire réservée au pool pour toute la durée du programme.
+    /// - `block_size`: Taille de chaque bloc, en octets.
+    ///
+    /// # Returns
+    ///
+    /// Le pool, ou `None` si `block_size` n'est pas un multiple non nul de la
+    /// taille d'un pointeur (chaque bloc libre doit pouvoir en contenir un).
+    pub fn new(memory: &'static mut [u8], block_size: usize) -> Option<Self> {
+        let word = mem::size_of::<usize>();
+        if block_size == 0 || !block_size.is_multiple_of(word) {
+            return None;
+        }
+        let len = memory.len();
+        let start = NonNull::from(memory).cast::<u8>();
+        let address = start.as_ptr().addr();
+        let skip = align_to(address, word) - address;
+        if skip > len {
+            return Some(Self::empty(start, block_size));
+        }
+
+        // La mémoire nous appartient jusqu'à la fin du programme et est alignée.
+        let arena = unsafe { Arena::new(start.byte_add(skip), len - skip, block_size) };
+        Some(Self { arena })
+    }
+
+    /// Crée un pool sans aucun bloc.
+    fn empty(start: NonNull<u8>, block_size: usize) -> Self {
+        let arena = unsafe { Arena::new(start, 0, block_size) };
+        Self { arena }
+    }
+
+    /// Alloue un bloc, ou retourne `None` si le pool est épuisé.
+    pub fn try_alloc(&mut self) -> Option<PoolBlock> {
+        let ptr = unsafe { self.arena.allocate() }?;
+        Some(PoolBlock {
+            ptr,
+            len: self.block_size(),
+        })
+    }
+
+    /// Rend un bloc au pool.
+    ///
+    /// # Returns
+    ///
+    /// `Err(block)` si le bloc n'a pas été alloué par ce pool ; il n'est alors
+    /// pas modifié.
+    pub fn free(&mut self, block: PoolBlock) -> Result<(), PoolBlock> {
+        if !self.arena.contains(block.as_ptr()) {
+            return Err(block);
+        }
+        // Le bloc provient de cette arène et son détenteur y renonce.
+        unsafe { self.arena.deallocate(block.ptr) };
+        Ok(())
+    }
+
+    /// Retourne la taille de chaque bloc, en octets.
+    pub fn block_size(&self) -> usize {
+        self.arena.block_size()
+    }
+
+    /// Retourne le nombre total de blocs du pool.
+    pub fn capacity(&self) -> usize {
+        self.arena.block_count()
+    }
+
+    /// Retourne le nombre de blocs actuellement libres.
+    pub fn available(&self) -> usize {
+        self.arena.free_count()
+    }
+}
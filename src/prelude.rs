@@ -0,0 +1,32 @@
+//! Types d'usage courant, à importer d'un bloc :
+//!
+//! ```rust
+//! use global_allocator::prelude::*;
+//!
+//! assert_eq!(BlockSize::try_from(64), Ok(BlockSize::Large));
+//! ```
+//!
+//! Seuls les types dont un programme a besoin pour initialiser et utiliser
+//! l'allocateur y figurent ; les outils de diagnostic (image du tas, rapport
+//! JSON, symboles) restent dans leurs modules.
+//!
+//! Les réexportations suivent l'ordre de celles de la racine du crate, par
+//! module d'origine, puis [`SlabAllocator`] qui y est défini.
+
+#[cfg(feature = "allocation-map")]
+pub use crate::{AllocationEntry, AllocationMapError};
+#[cfg(feature = "caller-tags")]
+pub use crate::{LiveBlock, TrackingOverflow};
+pub use crate::{ArenaConfig, BlockSize, InvalidBlockSize, ReusePolicy, ZeroSizePolicy};
+pub use crate::AllocationError;
+pub use crate::FatalPolicy;
+#[cfg(feature = "latency-budget")]
+pub use crate::LatencyBudget;
+pub use crate::SlabMemory;
+pub use crate::{FixedPool, PoolBlock};
+pub use crate::RegionError;
+#[cfg(feature = "reservations")]
+pub use crate::ReserveError;
+#[cfg(feature = "stats")]
+pub use crate::{ClassStats, MemoryStats};
+pub use crate::SlabAllocator;
@@ -0,0 +1,110 @@
+use core::ops::Range;
+
+/// Erreur retournée par
+/// [`SlabMemory::try_initialize`](crate::SlabMemory::try_initialize) lorsque la
+/// zone du tas est invalide ou que le tas est déjà initialisé.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RegionError {
+    /// L'adresse de début du tas est nulle.
+    Null,
+    /// Il ne reste aucun octet une fois le début aligné sur
+    /// [`SlabMemory::MIN_ALIGN`](crate::SlabMemory::MIN_ALIGN).
+    TooSmall,
+    /// La zone dépasse la fin de l'espace d'adressage.
+    WrapsAddressSpace,
+    /// La répartition du tas entre les arènes déborde : la somme des poids de
+    /// [`ArenaConfig`](crate::ArenaConfig), ou leur produit avec la taille du
+    /// tas, dépasse `usize`.
+    ConfigOverflow,
+    /// La zone chevauche la plage réservée d'index donné (pile, garde de
+    /// pile, zone d'un autre allocateur).
+    Overlaps(usize),
+    /// Le tas a déjà été initialisé : ses blocs peuvent être vivants, il ne
+    /// peut pas être reconstruit.
+    AlreadyInitialized,
+}
+
+impl RegionError {
+    /// Retourne le code stable et négatif de l'erreur, pour une interface FFI
+    /// (voir la table des codes du readme).
+    ///
+    /// [`RegionError::Null`] vaut `-64`, [`RegionError::TooSmall`] `-65`,
+    /// [`RegionError::WrapsAddressSpace`] `-66`,
+    /// [`RegionError::ConfigOverflow`] `-67` et
+    /// [`RegionError::AlreadyInitialized`] `-68` ; [`RegionError::Overlaps`] vaut
+    /// `-128` moins l'index de la plage, plafonné à 63.
+    pub const fn into_raw(self) -> i16 {
+        match self {
+            Self::Null => -64,
+            Self::TooSmall => -65,
+            Self::WrapsAddressSpace => -66,
+            Self::ConfigOverflow => -67,
+            Self::AlreadyInitialized => -68,
+            Self::Overlaps(index) => {
+                let index = if index < 63 { index } else { 63 };
+                -128 - index as i16
+            }
+        }
+    }
+
+    /// Retrouve l'erreur correspondant à un code, ou `None` s'il est inconnu.
+    pub const fn from_raw(code: i16) -> Option<Self> {
+        match code {
+            -64 => Some(Self::Null),
+            -65 => Some(Self::TooSmall),
+            -66 => Some(Self::WrapsAddressSpace),
+            -67 => Some(Self::ConfigOverflow),
+            -68 => Some(Self::AlreadyInitialized),
+            -191..=-128 => Some(Self::Overlaps((-128 - code) as usize)),
+            _ => None,
+        }
+    }
+
+    /// Retourne une courte description de l'erreur, à la manière de `strerror`.
+    ///
+    /// Disponible uniquement avec la feature `error-strings`.
+    #[cfg(feature = "error-strings")]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Null => "heap start is null",
+            Self::TooSmall => "heap too small",
+            Self::WrapsAddressSpace => "heap wraps the address space",
+            Self::ConfigOverflow => "arena shares overflow",
+            Self::Overlaps(_) => "heap overlaps a reserved range",
+            Self::AlreadyInitialized => "heap already initialized",
+        }
+    }
+}
+
+impl From<RegionError> for i16 {
+    fn from(error: RegionError) -> i16 {
+        error.into_raw()
+    }
+}
+
+impl TryFrom<i16> for RegionError {
+    type Error = i16;
+
+    /// Retrouve l'erreur d'un code, ou retourne le code s'il est inconnu.
+    fn try_from(code: i16) -> Result<Self, i16> {
+        Self::from_raw(code).ok_or(code)
+    }
+}
+
+/// Vérifie que la zone `[start, start + size)` peut accueillir le tas.
+///
+/// # Arguments
+///
+/// - `start`: Adresse de début de la zone, déjà alignée.
+/// - `size`: Taille de la zone, en octets.
+/// - `reserved`: Plages d'adresses que le tas ne doit pas chevaucher.
+pub(crate) fn validate(start: usize, size: usize, reserved: &[Range<usize>]) -> Result<(), RegionError> {
+    if size == 0 {
+        return Err(RegionError::TooSmall);
+    }
+    let end = start.checked_add(size).ok_or(RegionError::WrapsAddressSpace)?;
+    match reserved.iter().position(|range| range.start < end && start < range.end) {
+        Some(index) => Err(RegionError::Overlaps(index)),
+        None => Ok(()),
+    }
+}
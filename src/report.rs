@@ -0,0 +1,107 @@
+use crate::dump::DumpSink;
+use crate::stats::{ClassStats, MemoryStats};
+
+/// Version du rapport JSON produit par
+/// [`SlabMemory::report_json`](crate::SlabMemory::report_json).
+pub const REPORT_VERSION: usize = 1;
+
+/// Écrit un objet JSON champ par champ, sans allocation ni `core::fmt`.
+///
+/// Les clés sont des identifiants fixes : elles ne sont pas échappées.
+struct JsonObject<'a, S: DumpSink> {
+    /// Destination des octets.
+    sink: &'a mut S,
+    /// Indique si aucun champ n'a encore été écrit.
+    empty: bool,
+}
+
+impl<'a, S: DumpSink> JsonObject<'a, S> {
+    /// Ouvre un objet.
+    fn begin(sink: &'a mut S) -> Self {
+        sink.write_bytes(b"{");
+        Self { sink, empty: true }
+    }
+
+    /// Écrit la clé du champ suivant.
+    fn key(&mut self, key: &str) {
+        if !self.empty {
+            self.sink.write_bytes(b",");
+        }
+        self.empty = false;
+        self.sink.write_bytes(b"\"");
+        self.sink.write_bytes(key.as_bytes());
+        self.sink.write_bytes(b"\":");
+    }
+
+    /// Écrit un champ numérique.
+    fn number(&mut self, key: &str, value: usize) {
+        self.key(key);
+        write_number(self.sink, value);
+    }
+
+    /// Écrit un champ contenant un tableau d'objets, un par élément de `items`.
+    fn array<T>(
+        &mut self,
+        key: &str,
+        items: &[T],
+        mut write: impl FnMut(&mut JsonObject<'_, S>, &T),
+    ) {
+        self.key(key);
+        self.sink.write_bytes(b"[");
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.sink.write_bytes(b",");
+            }
+            let mut object = JsonObject::begin(&mut *self.sink);
+            write(&mut object, item);
+            object.end();
+        }
+        self.sink.write_bytes(b"]");
+    }
+
+    /// Ferme l'objet.
+    fn end(self) {
+        self.sink.write_bytes(b"}");
+    }
+}
+
+/// Écrit un entier en décimal.
+fn write_number<S: DumpSink>(sink: &mut S, mut value: usize) {
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    sink.write_bytes(&digits[start..]);
+}
+
+/// Écrit les statistiques d'une catégorie.
+fn write_class<S: DumpSink>(object: &mut JsonObject<'_, S>, class: &ClassStats) {
+    object.number("block_size", class.block_size);
+    object.number("capacity", class.capacity);
+    object.number("in_use", class.in_use);
+    object.number("allocations", class.allocations);
+    object.number("deallocations", class.deallocations);
+    object.number("failures", class.failures);
+    object.number("overhead_bytes", class.overhead_bytes);
+    object.number("layout_mismatches", class.layout_mismatches);
+    object.number("corrupt_headers", class.corrupt_headers);
+    object.number("fallbacks", class.fallbacks);
+}
+
+/// Écrit le rapport JSON complet de `stats` dans `sink`.
+pub(crate) fn write_json<S: DumpSink>(sink: &mut S, stats: &MemoryStats) {
+    let mut report = JsonObject::begin(sink);
+    report.number("version", REPORT_VERSION);
+    report.number("in_use_bytes", stats.in_use_bytes());
+    report.number("overhead_bytes", stats.overhead_bytes());
+    report.number("null_frees", stats.null_frees);
+    report.number("foreign_frees", stats.foreign_frees);
+    report.array("classes", &stats.classes, write_class);
+    report.end();
+}
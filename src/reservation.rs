@@ -0,0 +1,138 @@
+use core::ptr::NonNull;
+use crate::config::BlockSize;
+
+/// Nombre maximal de réservations nommées.
+pub const MAX_RESERVATIONS: usize = 8;
+
+/// Erreur retournée par [`SlabMemory::reserve`](crate::SlabMemory::reserve).
+///
+/// Chaque variante a un code stable et négatif, à la manière des codes
+/// d'erreur C, pour traverser une interface FFI (voir
+/// [`ReserveError::into_raw`] et la table des codes du readme).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(i16)]
+pub enum ReserveError {
+    /// Une réservation porte déjà ce nom.
+    DuplicateName = -32,
+    /// Les [`MAX_RESERVATIONS`] réservations sont déjà utilisées.
+    TableFull = -33,
+    /// L'arène n'a pas assez de blocs libres ; aucun bloc n'a été réservé.
+    OutOfMemory = -34,
+    /// Le plan d'allocation installé (feature `allocation-map`) prévoit des
+    /// allocations de cette catégorie.
+    PlannedClass = -35,
+}
+
+impl ReserveError {
+    /// Retourne le code stable de l'erreur.
+    pub const fn into_raw(self) -> i16 {
+        self as i16
+    }
+
+    /// Retrouve l'erreur correspondant à un code, ou `None` s'il est inconnu.
+    ///
+    /// # Exemple
+    ///
+    /// ```rust
+    /// use global_allocator::ReserveError;
+    ///
+    /// let code = ReserveError::TableFull.into_raw();
+    /// assert_eq!(ReserveError::from_raw(code), Some(ReserveError::TableFull));
+    /// assert_eq!(ReserveError::from_raw(0), None);
+    /// ```
+    pub const fn from_raw(code: i16) -> Option<Self> {
+        match code {
+            -32 => Some(Self::DuplicateName),
+            -33 => Some(Self::TableFull),
+            -34 => Some(Self::OutOfMemory),
+            -35 => Some(Self::PlannedClass),
+            _ => None,
+        }
+    }
+
+    /// Retourne une courte description de l'erreur, à la manière de `strerror`.
+    ///
+    /// Disponible uniquement avec la feature `error-strings`.
+    #[cfg(feature = "error-strings")]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::DuplicateName => "reservation name already used",
+            Self::TableFull => "reservation table full",
+            Self::OutOfMemory => "not enough free blocks",
+            Self::PlannedClass => "class covered by the allocation map",
+        }
+    }
+}
+
+impl From<ReserveError> for i16 {
+    fn from(error: ReserveError) -> i16 {
+        error.into_raw()
+    }
+}
+
+impl TryFrom<i16> for ReserveError {
+    type Error = i16;
+
+    /// Retrouve l'erreur d'un code, ou retourne le code s'il est inconnu.
+    fn try_from(code: i16) -> Result<Self, i16> {
+        Self::from_raw(code).ok_or(code)
+    }
+}
+
+/// Blocs d'une catégorie mis de côté sous un nom.
+///
+/// Les blocs réservés sont chaînés par leur premier mot, comme dans une arène.
+pub struct Reservation {
+    /// Nom de la réservation.
+    name: &'static str,
+    /// Catégorie des blocs réservés.
+    block_size: BlockSize,
+    /// Premier bloc réservé, ou `None` si la réservation est épuisée.
+    head: Option<NonNull<u8>>,
+}
+
+// Une réservation ne contient que des blocs qui lui appartiennent.
+unsafe impl Send for Reservation {}
+
+// Une réservation n'est jamais accédée sans son verrou.
+unsafe impl Sync for Reservation {}
+
+impl Reservation {
+    /// Crée une réservation vide.
+    pub fn new(name: &'static str, block_size: BlockSize) -> Self {
+        Self {
+            name,
+            block_size,
+            head: None,
+        }
+    }
+
+    /// Retourne le nom de la réservation.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Retourne la catégorie des blocs réservés.
+    pub fn block_size(&self) -> BlockSize {
+        self.block_size
+    }
+
+    /// Ajoute un bloc à la réservation.
+    ///
+    /// # Safety
+    ///
+    /// `block` doit être un bloc libre de la catégorie de la réservation, qui
+    /// lui appartient désormais.
+    pub unsafe fn push(&mut self, block: NonNull<u8>) {
+        unsafe { block.cast::<Option<NonNull<u8>>>().write(self.head) };
+        self.head = Some(block);
+    }
+
+    /// Retire un bloc de la réservation, s'il en reste.
+    pub fn pop(&mut self) -> Option<NonNull<u8>> {
+        let block = self.head?;
+        // Les blocs de la liste appartiennent à la réservation.
+        self.head = unsafe { block.cast::<Option<NonNull<u8>>>().read() };
+        Some(block)
+    }
+}
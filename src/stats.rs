@@ -0,0 +1,221 @@
+#[cfg(feature = "stats")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "stats")]
+use crate::config::BlockSize;
+
+/// Statistiques d'utilisation d'une catégorie de blocs.
+#[cfg(feature = "stats")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ClassStats {
+    /// Taille des blocs de la catégorie, en octets.
+    pub block_size: usize,
+    /// Nombre total de blocs de l'arène (`0` si elle n'est pas initialisée).
+    pub capacity: usize,
+    /// Nombre de blocs actuellement alloués.
+    pub in_use: usize,
+    /// Nombre total d'allocations réussies.
+    pub allocations: usize,
+    /// Nombre total de libérations.
+    pub deallocations: usize,
+    /// Nombre total d'allocations ayant échoué.
+    pub failures: usize,
+    /// Octets des blocs alloués occupés par les en-têtes (feature `block-header`).
+    pub overhead_bytes: usize,
+    /// Libérations dont le layout ne correspondait pas à la catégorie du bloc.
+    pub layout_mismatches: usize,
+    /// Libérations refusées car l'en-tête du bloc était corrompu.
+    pub corrupt_headers: usize,
+    /// Allocations de cette catégorie servies par la catégorie suivante
+    /// (voir [`SlabMemory::set_fallback`](crate::SlabMemory::set_fallback)).
+    pub fallbacks: usize,
+}
+
+/// Statistiques d'utilisation de l'ensemble du tas.
+///
+/// Obtenues via [`SlabMemory::stats`](crate::SlabMemory::stats).
+#[cfg(feature = "stats")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MemoryStats {
+    /// Statistiques de chaque catégorie, par taille de bloc croissante.
+    pub classes: [ClassStats; 8],
+    /// Libérations d'un pointeur nul, ignorées.
+    pub null_frees: usize,
+    /// Libérations d'un pointeur hors de toute arène ou à l'intérieur d'un
+    /// bloc, ignorées.
+    pub foreign_frees: usize,
+}
+
+#[cfg(feature = "stats")]
+impl MemoryStats {
+    /// Retourne le nombre total d'octets de blocs alloués, en-têtes compris.
+    pub fn in_use_bytes(&self) -> usize {
+        self.classes.iter().map(|c| c.in_use * c.block_size).sum()
+    }
+
+    /// Retourne le nombre total d'octets occupés par les en-têtes de blocs.
+    pub fn overhead_bytes(&self) -> usize {
+        self.classes.iter().map(|c| c.overhead_bytes).sum()
+    }
+}
+
+/// Compteurs bruts d'une catégorie, mis à jour par l'allocateur.
+///
+/// Disposition stable (`repr(C)`), lue telle quelle par les outils de
+/// débogage via le symbole `__slab_stats` (feature `gdb-symbols`).
+#[cfg(feature = "stats")]
+#[repr(C)]
+pub(crate) struct ClassCounters {
+    /// Allocations réussies.
+    allocations: AtomicUsize,
+    /// Libérations.
+    deallocations: AtomicUsize,
+    /// Allocations échouées.
+    failures: AtomicUsize,
+    /// Libérations avec un layout d'une autre catégorie.
+    layout_mismatches: AtomicUsize,
+    /// Libérations refusées pour en-tête corrompu.
+    corrupt_headers: AtomicUsize,
+    /// Allocations servies par la catégorie suivante.
+    fallbacks: AtomicUsize,
+}
+
+#[cfg(feature = "stats")]
+impl ClassCounters {
+    /// Crée des compteurs à zéro.
+    const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            deallocations: AtomicUsize::new(0),
+            failures: AtomicUsize::new(0),
+            layout_mismatches: AtomicUsize::new(0),
+            corrupt_headers: AtomicUsize::new(0),
+            fallbacks: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// Compteurs de chaque catégorie, indexés comme les arènes.
+///
+/// Avec la feature `gdb-symbols`, exportés sous le symbole `__slab_stats` :
+/// 8 entrées de 6 mots (allocations, libérations, échecs, layouts
+/// incohérents, en-têtes corrompus, replis), par taille de bloc croissante.
+/// Exemple sous GDB : `x/48xw &__slab_stats`.
+#[cfg(feature = "stats")]
+#[cfg_attr(feature = "gdb-symbols", export_name = "__slab_stats")]
+pub(crate) static COUNTERS: [ClassCounters; 8] = [const { ClassCounters::new() }; 8];
+
+/// Libérations d'un pointeur nul.
+#[cfg(feature = "stats")]
+static NULL_FREES: AtomicUsize = AtomicUsize::new(0);
+
+/// Libérations d'un pointeur qui ne désigne aucun bloc.
+#[cfg(feature = "stats")]
+static FOREIGN_FREES: AtomicUsize = AtomicUsize::new(0);
+
+/// Enregistre une allocation réussie dans la catégorie d'index `index`.
+#[cfg(feature = "stats")]
+pub(crate) fn record_allocation(index: usize) {
+    COUNTERS[index].allocations.fetch_add(1, Ordering::Relaxed);
+}
+
+// Sans la feature `stats`, les compteurs ne sont pas compilés et les appels
+// `record_*` ne coûtent rien sur le chemin d'allocation.
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_allocation(_index: usize) {}
+
+/// Enregistre une libération dans la catégorie d'index `index`.
+#[cfg(feature = "stats")]
+pub(crate) fn record_deallocation(index: usize) {
+    COUNTERS[index].deallocations.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_deallocation(_index: usize) {}
+
+/// Enregistre une allocation échouée dans la catégorie d'index `index`.
+#[cfg(feature = "stats")]
+pub(crate) fn record_failure(index: usize) {
+    COUNTERS[index].failures.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_failure(_index: usize) {}
+
+/// Enregistre une libération dont le layout désignait une autre catégorie.
+#[cfg(feature = "stats")]
+pub(crate) fn record_layout_mismatch(index: usize) {
+    COUNTERS[index].layout_mismatches.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_layout_mismatch(_index: usize) {}
+
+/// Enregistre une libération refusée pour en-tête corrompu.
+#[cfg(all(feature = "stats", feature = "block-header"))]
+pub(crate) fn record_corrupt_header(index: usize) {
+    COUNTERS[index].corrupt_headers.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(all(not(feature = "stats"), feature = "block-header"))]
+pub(crate) fn record_corrupt_header(_index: usize) {}
+
+/// Enregistre une allocation de la catégorie d'index `index` servie par la
+/// catégorie suivante.
+#[cfg(feature = "stats")]
+pub(crate) fn record_fallback(index: usize) {
+    COUNTERS[index].fallbacks.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_fallback(_index: usize) {}
+
+/// Enregistre la libération d'un pointeur nul.
+#[cfg(feature = "stats")]
+pub(crate) fn record_null_free() {
+    NULL_FREES.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_null_free() {}
+
+/// Enregistre la libération d'un pointeur qui ne désigne aucun bloc.
+#[cfg(feature = "stats")]
+pub(crate) fn record_foreign_free() {
+    FOREIGN_FREES.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "stats"))]
+pub(crate) fn record_foreign_free() {}
+
+/// Retourne les compteurs de libérations ignorées : pointeurs nuls, puis
+/// pointeurs qui ne désignent aucun bloc.
+#[cfg(feature = "stats")]
+pub(crate) fn ignored_frees() -> (usize, usize) {
+    (NULL_FREES.load(Ordering::Relaxed), FOREIGN_FREES.load(Ordering::Relaxed))
+}
+
+/// Construit les statistiques de la catégorie d'index `index`.
+///
+/// # Arguments
+///
+/// - `index`: Index de la catégorie.
+/// - `capacity`: Nombre total de blocs de l'arène correspondante.
+#[cfg(feature = "stats")]
+pub(crate) fn snapshot(index: usize, capacity: usize) -> ClassStats {
+    let counters = &COUNTERS[index];
+    let allocations = counters.allocations.load(Ordering::Relaxed);
+    let deallocations = counters.deallocations.load(Ordering::Relaxed);
+    let in_use = allocations.saturating_sub(deallocations);
+    ClassStats {
+        block_size: BlockSize::ALL[index].size(),
+        capacity,
+        in_use,
+        allocations,
+        deallocations,
+        failures: counters.failures.load(Ordering::Relaxed),
+        overhead_bytes: in_use * crate::header::HEADER_SIZE,
+        layout_mismatches: counters.layout_mismatches.load(Ordering::Relaxed),
+        corrupt_headers: counters.corrupt_headers.load(Ordering::Relaxed),
+        fallbacks: counters.fallbacks.load(Ordering::Relaxed),
+    }
+}
@@ -0,0 +1,105 @@
+use core::sync::atomic::{AtomicPtr, AtomicU32, AtomicUsize, Ordering};
+use crate::arena::Arena;
+use crate::config::BlockSize;
+
+/// Signature de [`SLAB_ARENA_TABLE`] : `"SLBT"` lu comme un `u32` little-endian.
+const TABLE_MAGIC: u32 = u32::from_le_bytes(*b"SLBT");
+
+/// Version de la disposition de [`SLAB_ARENA_TABLE`].
+const TABLE_VERSION: u32 = 1;
+
+/// Description d'une arène dans la table exportée.
+///
+/// Tous les champs ont la taille d'un mot de la cible.
+#[repr(C)]
+pub(crate) struct ExportedArenaEntry {
+    /// Adresse de début de l'arène (`0` si elle n'est pas initialisée).
+    start: AtomicPtr<u8>,
+    /// Taille des blocs, en octets.
+    block_size: AtomicUsize,
+    /// Nombre de blocs de l'arène.
+    block_count: AtomicUsize,
+    /// Adresse du mot de tête de la liste des blocs libres.
+    free_head: AtomicPtr<AtomicUsize>,
+}
+
+/// Table des arènes lisible par un débogueur.
+#[repr(C)]
+pub(crate) struct ExportedArenaTable {
+    /// Signature `"SLBT"`.
+    magic: AtomicU32,
+    /// Version de la disposition (`1`).
+    version: AtomicU32,
+    /// Nombre d'entrées de `entries`.
+    count: AtomicUsize,
+    /// Une entrée par catégorie, par taille de bloc croissante.
+    entries: [ExportedArenaEntry; 8],
+}
+
+/// Table des arènes exportée sous le symbole `__slab_arena_table`.
+///
+/// Permet à un script GDB ou à un outil de sonde (probe-rs) de lire l'état
+/// du tas sans exécuter de code sur la cible, même lorsqu'elle est bloquée.
+/// La table est mise à jour par [`SlabMemory::initialize`](crate::SlabMemory::initialize).
+///
+/// # Disposition
+///
+/// `W` désigne la taille d'un mot de la cible (4 octets sur Cortex-M).
+///
+/// | Offset      | Taille | Contenu                         |
+/// |-------------|--------|---------------------------------|
+/// | 0           | 4      | Signature `"SLBT"`              |
+/// | 4           | 4      | Version (`1`)                   |
+/// | 8           | W      | Nombre d'entrées `N` (`8`)      |
+/// | 8 + W       | 4W × N | Entrées                         |
+///
+/// Chaque entrée contient, dans l'ordre, quatre mots : adresse de début,
+/// taille de bloc, nombre de blocs, adresse du mot de tête de liste libre.
+///
+/// Le mot de tête contient dans sa moitié basse l'index du premier bloc
+/// libre plus un (`0` si la liste est vide) et dans sa moitié haute une
+/// étiquette sans signification pour un lecteur. Chaque bloc libre commence
+/// par l'adresse du bloc libre suivant (`0` en fin de liste).
+///
+/// Exemple sous GDB : `x/35xw &__slab_arena_table`.
+#[export_name = "__slab_arena_table"]
+pub(crate) static SLAB_ARENA_TABLE: ExportedArenaTable = ExportedArenaTable {
+    magic: AtomicU32::new(TABLE_MAGIC),
+    version: AtomicU32::new(TABLE_VERSION),
+    count: AtomicUsize::new(8),
+    entries: [const {
+        ExportedArenaEntry {
+            start: AtomicPtr::new(core::ptr::null_mut()),
+            block_size: AtomicUsize::new(0),
+            block_count: AtomicUsize::new(0),
+            free_head: AtomicPtr::new(core::ptr::null_mut()),
+        }
+    }; 8],
+};
+
+/// Publie la description d'une arène dans la table exportée.
+///
+/// # Arguments
+///
+/// - `index`: Index de la catégorie.
+/// - `arena`: Arène de la catégorie, ou `None` si elle n'est pas initialisée.
+///   Elle ne doit plus être déplacée après cet appel.
+pub(crate) fn publish(index: usize, arena: Option<&Arena>) {
+    let entry = &SLAB_ARENA_TABLE.entries[index];
+    match arena {
+        Some(arena) => {
+            entry.start.store(arena.start().as_ptr(), Ordering::Relaxed);
+            entry.block_size.store(arena.block_size(), Ordering::Relaxed);
+            entry.block_count.store(arena.block_count(), Ordering::Relaxed);
+            entry
+                .free_head
+                .store(arena.free_list_head() as *mut AtomicUsize, Ordering::Relaxed);
+        }
+        None => {
+            entry.start.store(core::ptr::null_mut(), Ordering::Relaxed);
+            entry.block_size.store(BlockSize::ALL[index].size(), Ordering::Relaxed);
+            entry.block_count.store(0, Ordering::Relaxed);
+            entry.free_head.store(core::ptr::null_mut(), Ordering::Relaxed);
+        }
+    }
+}
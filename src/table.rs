@@ -0,0 +1,135 @@
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use crate::arena::Arena;
+use crate::config::BlockSize;
+use crate::header::HEADER_SIZE;
+#[cfg(feature = "lock-free")]
+use crate::lock::SharedGuard;
+use crate::lock::{SpinLock, SpinLockGuard};
+
+/// Table des arènes de l'allocateur, indexée par catégorie.
+///
+/// Seul point d'accès aux arènes : chaque accès passe par le verrou de
+/// l'arène concernée, et la table sait si elle a été initialisée. Seule
+/// [`ArenaTable::install`] est `unsafe`.
+pub(crate) struct ArenaTable {
+    /// Arènes de chaque catégorie, `None` avant l'initialisation.
+    arenas: [SpinLock<Option<Arena>>; 8],
+    /// Plage d'adresses `[début, fin)` des blocs de chaque arène.
+    ///
+    /// Permet de retrouver l'arène propriétaire d'un bloc sans prendre de
+    /// verrou.
+    ranges: [[AtomicUsize; 2]; 8],
+    /// Indique si [`ArenaTable::install`] a été appelé.
+    initialized: AtomicBool,
+}
+
+impl ArenaTable {
+    /// Crée une table dont aucune arène n'est initialisée.
+    pub(crate) const fn new() -> Self {
+        Self {
+            arenas: [const { SpinLock::new(None) }; 8],
+            ranges: [const { [const { AtomicUsize::new(0) }; 2] }; 8],
+            initialized: AtomicBool::new(false),
+        }
+    }
+
+    /// Verrouille l'arène d'index `index`.
+    pub(crate) fn lock(&self, index: usize) -> SpinLockGuard<'_, Option<Arena>> {
+        self.arenas[index].lock()
+    }
+
+    /// Obtient un accès partagé à l'arène d'index `index`.
+    ///
+    /// Disponible uniquement avec la feature `lock-free`.
+    #[cfg(feature = "lock-free")]
+    pub(crate) fn lock_shared(&self, index: usize) -> SharedGuard<'_, Option<Arena>> {
+        self.arenas[index].lock_shared()
+    }
+
+    /// Verrouille toutes les arènes, par index croissant.
+    ///
+    /// Toute opération portant sur plusieurs arènes doit passer par cette
+    /// méthode : l'ordre d'acquisition fixe évite les interblocages entre ces
+    /// opérations.
+    pub(crate) fn lock_all(&self) -> [SpinLockGuard<'_, Option<Arena>>; 8] {
+        core::array::from_fn(|i| self.arenas[i].lock())
+    }
+
+    /// Crée l'arène d'index `index` sur `capacity` octets à partir de `start`
+    /// et publie sa plage d'adresses.
+    ///
+    /// `guard` doit être la garde de cette même arène, obtenue par
+    /// [`ArenaTable::lock_all`].
+    ///
+    /// # Safety
+    ///
+    /// Mêmes conditions que [`Arena::new`].
+    pub(crate) unsafe fn install(
+        &self,
+        guard: &mut SpinLockGuard<'_, Option<Arena>>,
+        index: usize,
+        start: NonNull<u8>,
+        capacity: usize,
+    ) {
+        let block_size = BlockSize::ALL[index].size();
+        let arena = guard.insert(unsafe { Arena::new(start, capacity, block_size) });
+        // La plage publiée s'arrête au dernier bloc géré : l'arène ignore la
+        // mémoire au-delà de sa limite de blocs.
+        let end_address = start.as_ptr().addr() + arena.block_count() * block_size;
+        let [first, end] = &self.ranges[index];
+        first.store(start.as_ptr().addr(), Ordering::Release);
+        end.store(end_address, Ordering::Release);
+        self.initialized.store(true, Ordering::Release);
+    }
+
+    /// Indique si au moins une arène a été initialisée.
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.initialized.load(Ordering::Acquire)
+    }
+
+    /// Retourne l'alignement garanti des pointeurs remis par l'arène d'index
+    /// `index`.
+    ///
+    /// Les blocs se suivent à intervalle de leur taille depuis le début de
+    /// l'arène, et l'en-tête (feature `block-header`) décale le pointeur remis
+    /// de [`HEADER_SIZE`] octets : l'alignement est la plus grande puissance de
+    /// deux qui divise à la fois la taille de bloc et l'adresse du premier
+    /// pointeur.
+    pub(crate) fn alignment(&self, index: usize) -> usize {
+        let first = self.ranges[index][0].load(Ordering::Acquire) + HEADER_SIZE;
+        1 << (BlockSize::ALL[index].size() | first).trailing_zeros()
+    }
+
+    /// Retourne la catégorie de l'arène contenant `address`, ou `None` si
+    /// l'adresse n'appartient à aucune arène.
+    ///
+    /// Les arènes sont contiguës et placées par adresses décroissantes avec
+    /// leur index (voir [`SlabMemory::initialize_with`]) : une recherche
+    /// dichotomique sur les débuts de plage trouve la seule candidate en trois
+    /// comparaisons, sans verrou.
+    ///
+    /// [`SlabMemory::initialize_with`]: crate::SlabMemory::initialize_with
+    pub(crate) fn owner_of(&self, address: usize) -> Option<BlockSize> {
+        let index = self
+            .ranges
+            .partition_point(|[start, _]| start.load(Ordering::Acquire) > address);
+        let [_, end] = self.ranges.get(index)?;
+        if address >= end.load(Ordering::Acquire) {
+            return None;
+        }
+        BlockSize::from_index(index)
+    }
+
+    /// Retourne la catégorie de l'arène dont `address` est le début d'un
+    /// bloc, ou `None` si l'adresse est hors de toute arène ou à l'intérieur
+    /// d'un bloc.
+    ///
+    /// Même vérification que [`Arena::contains`], sur les plages publiées
+    /// par [`ArenaTable::install`] et donc sans verrou.
+    pub(crate) fn block_owner(&self, address: usize) -> Option<BlockSize> {
+        let owner = self.owner_of(address)?;
+        let start = self.ranges[owner.index()][0].load(Ordering::Acquire);
+        (address - start).is_multiple_of(owner.size()).then_some(owner)
+    }
+}
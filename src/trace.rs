@@ -0,0 +1,104 @@
+#[cfg(feature = "panic-handler")]
+use core::fmt::Write;
+use core::ptr::NonNull;
+#[cfg(feature = "panic-handler")]
+use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+#[cfg(feature = "panic-handler")]
+use crate::config::BlockSize;
+
+/// Nombre d'événements gardés pour le rapport de panique.
+#[cfg(feature = "panic-handler")]
+const TRACE_EVENTS: usize = 16;
+
+/// Bloc remis à l'appelant. Un emplacement encore vide porte la nature `0`.
+#[cfg(feature = "panic-handler")]
+const ALLOCATION: u8 = 1;
+/// Bloc rendu à son arène.
+#[cfg(feature = "panic-handler")]
+const DEALLOCATION: u8 = 2;
+/// Allocation échouée.
+#[cfg(feature = "panic-handler")]
+const FAILURE: u8 = 3;
+
+/// Un événement de l'anneau.
+///
+/// Les champs sont écrits séparément, sans verrou : un événement lu pendant
+/// qu'un autre contexte le remplace peut mêler les deux. Le rapport de
+/// panique s'en accommode plutôt que de risquer d'attendre un verrou.
+#[cfg(feature = "panic-handler")]
+struct Event {
+    /// Nature de l'événement.
+    kind: AtomicU8,
+    /// Index de la catégorie concernée.
+    class: AtomicU8,
+    /// Adresse du bloc, `0` pour un échec.
+    addr: AtomicUsize,
+}
+
+/// Derniers événements, écrits de façon circulaire.
+#[cfg(feature = "panic-handler")]
+static EVENTS: [Event; TRACE_EVENTS] = [const {
+    Event {
+        kind: AtomicU8::new(0),
+        class: AtomicU8::new(0),
+        addr: AtomicUsize::new(0),
+    }
+}; TRACE_EVENTS];
+
+/// Nombre total d'événements enregistrés ; le suivant va dans
+/// `EVENTS[NEXT % TRACE_EVENTS]`.
+#[cfg(feature = "panic-handler")]
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+/// Ajoute un événement à l'anneau, en écrasant le plus ancien.
+#[cfg(feature = "panic-handler")]
+fn push(kind: u8, index: usize, addr: usize) {
+    let event = &EVENTS[NEXT.fetch_add(1, Ordering::Relaxed) % TRACE_EVENTS];
+    event.kind.store(kind, Ordering::Relaxed);
+    event.class.store(index as u8, Ordering::Relaxed);
+    event.addr.store(addr, Ordering::Relaxed);
+}
+
+/// Enregistre le bloc `ptr` remis dans la catégorie d'index `index`.
+#[cfg(feature = "panic-handler")]
+pub(crate) fn record_allocation(index: usize, ptr: NonNull<u8>) {
+    push(ALLOCATION, index, ptr.addr().get());
+}
+
+#[cfg(not(feature = "panic-handler"))]
+pub(crate) fn record_allocation(_index: usize, _ptr: NonNull<u8>) {}
+
+/// Enregistre le bloc `ptr` rendu à la catégorie d'index `index`.
+#[cfg(feature = "panic-handler")]
+pub(crate) fn record_deallocation(index: usize, ptr: NonNull<u8>) {
+    push(DEALLOCATION, index, ptr.addr().get());
+}
+
+#[cfg(not(feature = "panic-handler"))]
+pub(crate) fn record_deallocation(_index: usize, _ptr: NonNull<u8>) {}
+
+/// Enregistre une allocation échouée dans la catégorie d'index `index`.
+#[cfg(feature = "panic-handler")]
+pub(crate) fn record_failure(index: usize) {
+    push(FAILURE, index, 0);
+}
+
+#[cfg(not(feature = "panic-handler"))]
+pub(crate) fn record_failure(_index: usize) {}
+
+/// Écrit les derniers événements, du plus ancien au plus récent.
+#[cfg(feature = "panic-handler")]
+pub(crate) fn write_recent<W: Write>(writer: &mut W) {
+    let total = NEXT.load(Ordering::Relaxed);
+    for n in total.saturating_sub(TRACE_EVENTS)..total {
+        let event = &EVENTS[n % TRACE_EVENTS];
+        let size = BlockSize::from_index(event.class.load(Ordering::Relaxed) as usize).map_or(0, BlockSize::size);
+        let addr = event.addr.load(Ordering::Relaxed);
+        let _ = match event.kind.load(Ordering::Relaxed) {
+            ALLOCATION => writeln!(writer, "trace: alloc {:#x} ({} bytes)", addr, size),
+            DEALLOCATION => writeln!(writer, "trace: free {:#x} ({} bytes)", addr, size),
+            FAILURE => writeln!(writer, "trace: failed alloc ({} bytes)", size),
+            _ => Ok(()),
+        };
+    }
+}
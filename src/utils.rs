@@ -15,7 +15,7 @@
 ///
 /// # Exemple
 ///
-/// ```rust
+/// ```rust,ignore
 /// use my_allocator::utils::align_to;
 ///
 /// let aligned = align_to(13, 8);
@@ -27,3 +27,51 @@
 pub fn align_to(value: usize, alignment: usize) -> usize {
     (value + alignment - 1) & !(alignment - 1)
 }
+
+/// Lit un entier décimal fourni à la compilation par la variable
+/// d'environnement `$name`, ou retourne `$default` si elle n'est pas définie.
+///
+/// Une valeur vide, non décimale ou qui déborde d'un `usize` est une erreur
+/// de compilation qui nomme la variable.
+macro_rules! env_usize {
+    ($name:literal, $default:expr) => {
+        $crate::utils::parse_decimal(
+            option_env!($name),
+            $default,
+            concat!($name, " must be a decimal integer that fits in usize"),
+        )
+    };
+}
+pub(crate) use env_usize;
+
+/// Convertit `value` en entier décimal, ou retourne `default` si elle est
+/// absente.
+///
+/// Destinée à [`env_usize!`] et évaluée à la compilation : une valeur vide,
+/// non décimale ou qui déborde d'un `usize` arrête la compilation avec
+/// `message`.
+pub(crate) const fn parse_decimal(value: Option<&str>, default: usize, message: &str) -> usize {
+    let Some(value) = value else {
+        return default;
+    };
+    let digits = value.as_bytes();
+    if digits.is_empty() {
+        panic!("{}", message);
+    }
+    let mut result: usize = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        let next = match result.checked_mul(10) {
+            Some(shifted) if digits[i].is_ascii_digit() => {
+                shifted.checked_add((digits[i] - b'0') as usize)
+            }
+            _ => None,
+        };
+        result = match next {
+            Some(next) => next,
+            None => panic!("{}", message),
+        };
+        i += 1;
+    }
+    result
+}
@@ -0,0 +1,26 @@
+//! Demandes plus alignées que les pointeurs de leur catégorie.
+
+mod common;
+
+use core::alloc::Layout;
+use global_allocator::SlabMemory;
+
+#[test]
+fn over_aligned_requests_fail() {
+    let _guard = common::setup();
+
+    let layout = Layout::from_size_align(40, 64).unwrap();
+    let ptr = unsafe { SlabMemory::allocate(layout) };
+    if cfg!(feature = "block-header") {
+        // Le pointeur suit l'en-tête : il n'est aligné que sur 8 octets.
+        assert!(ptr.is_null());
+    } else {
+        assert!(!ptr.is_null());
+        assert!(ptr.addr().is_multiple_of(64));
+        unsafe { SlabMemory::deallocate(ptr, layout) };
+    }
+
+    // Un bloc de 64 octets n'est jamais aligné sur 128.
+    let layout = Layout::from_size_align(40, 128).unwrap();
+    assert!(unsafe { SlabMemory::allocate(layout) }.is_null());
+}
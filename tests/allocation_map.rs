@@ -0,0 +1,33 @@
+//! `SlabMemory::can_allocate` respecte les limites du plan d'allocation.
+#![cfg(feature = "allocation-map")]
+
+mod common;
+
+use common::layout;
+use global_allocator::{allocation_map, SlabMemory};
+
+allocation_map!(static MAP = [(24, 2)]);
+
+#[test]
+fn can_allocate_follows_the_map() {
+    let _guard = common::setup();
+    SlabMemory::set_allocation_map(&MAP).unwrap();
+
+    let layout = layout(24);
+    // Hors du plan : refusée bien que l'arène ait des blocs libres.
+    assert!(!SlabMemory::can_allocate(common::layout(40)));
+
+    let first = unsafe { SlabMemory::allocate(layout) };
+    assert!(SlabMemory::can_allocate(layout));
+    let second = unsafe { SlabMemory::allocate(layout) };
+    assert!(!first.is_null() && !second.is_null());
+    // Les deux allocations prévues sont en cours.
+    assert!(!SlabMemory::can_allocate(layout));
+    assert!(unsafe { SlabMemory::allocate(layout) }.is_null());
+
+    unsafe { SlabMemory::deallocate(first, layout) };
+    assert!(SlabMemory::can_allocate(layout));
+    // La question ne compte pas de violation.
+    assert_eq!(SlabMemory::allocation_map_violations(), 1);
+    unsafe { SlabMemory::deallocate(second, layout) };
+}
@@ -0,0 +1,55 @@
+//! `SlabMemory::can_allocate` suit les mêmes règles que `SlabMemory::allocate`.
+
+mod common;
+
+use core::alloc::Layout;
+use common::{layout, setup};
+use global_allocator::{BlockSize, SlabMemory, ZeroSizePolicy};
+
+#[test]
+fn zero_size_follows_the_policy() {
+    let _guard = setup();
+    for (policy, expected) in [
+        (ZeroSizePolicy::Dangling, true),
+        (ZeroSizePolicy::Block, true),
+        (ZeroSizePolicy::Null, false),
+    ] {
+        SlabMemory::set_zero_size_policy(policy);
+        assert_eq!(SlabMemory::can_allocate(layout(0)), expected, "{:?}", policy);
+        let ptr = unsafe { SlabMemory::allocate(layout(0)) };
+        assert_eq!(!ptr.is_null(), expected, "{:?}", policy);
+        unsafe { SlabMemory::deallocate(ptr, layout(0)) };
+    }
+    SlabMemory::set_zero_size_policy(ZeroSizePolicy::Dangling);
+}
+
+#[test]
+fn exhausted_class_uses_the_fallback() {
+    let _guard = setup();
+    let request = layout(500);
+    let blocks: Vec<_> = (0..SlabMemory::available(BlockSize::Colossal))
+        .map(|_| unsafe { SlabMemory::allocate(request) })
+        .collect();
+    assert!(blocks.iter().all(|ptr| !ptr.is_null()));
+    assert!(!SlabMemory::can_allocate(request));
+
+    SlabMemory::set_fallback(BlockSize::Colossal, true);
+    assert!(SlabMemory::can_allocate(request));
+    let borrowed = unsafe { SlabMemory::allocate(request) };
+    assert_eq!(SlabMemory::arena_of(borrowed), Some(BlockSize::Mammoth));
+
+    unsafe { SlabMemory::deallocate(borrowed, request) };
+    SlabMemory::set_fallback(BlockSize::Colossal, false);
+    for ptr in blocks {
+        unsafe { SlabMemory::deallocate(ptr, request) };
+    }
+    assert!(SlabMemory::can_allocate(request));
+}
+
+#[test]
+fn over_aligned_request_is_refused() {
+    let _guard = setup();
+    let request = Layout::from_size_align(40, 128).unwrap();
+    assert!(!SlabMemory::can_allocate(request));
+    assert!(unsafe { SlabMemory::allocate(request) }.is_null());
+}
@@ -0,0 +1,31 @@
+//! Mise en place commune aux tests d'intégration.
+//!
+//! L'allocateur est global et ne s'initialise qu'une fois : les tests d'un
+//! même binaire partagent un même tas et s'exécutent l'un après l'autre sous
+//! `LOCK`.
+#![allow(dead_code)]
+
+use core::alloc::Layout;
+use std::sync::{Mutex, MutexGuard, Once};
+use global_allocator::SlabMemory;
+
+static LOCK: Mutex<()> = Mutex::new(());
+static INIT: Once = Once::new();
+
+#[repr(align(1024))]
+struct Heap([u8; 16 * 1024]);
+
+/// Prend le verrou des tests et initialise le tas au premier appel.
+pub fn setup() -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    INIT.call_once(|| {
+        let heap = Box::leak(Box::new(Heap([0; 16 * 1024])));
+        unsafe { SlabMemory::initialize(heap.0.as_mut_ptr(), heap.0.len()) };
+    });
+    guard
+}
+
+/// Disposition de `size` octets alignée sur 8.
+pub fn layout(size: usize) -> Layout {
+    Layout::from_size_align(size, 8).unwrap()
+}
@@ -0,0 +1,20 @@
+//! Shims C de la feature `ffi`.
+#![cfg(all(feature = "ffi", feature = "stats"))]
+
+mod common;
+
+use core::ffi::c_void;
+use global_allocator::{ffi, SlabMemory};
+
+#[test]
+fn realloc_of_a_foreign_pointer_is_a_foreign_free() {
+    let _guard = common::setup();
+
+    let mut outside = [0u8; 32];
+    let foreign = outside.as_mut_ptr().cast::<c_void>();
+    let before = SlabMemory::stats().foreign_frees;
+    assert!(unsafe { ffi::realloc(foreign, 64) }.is_null());
+    assert_eq!(SlabMemory::stats().foreign_frees, before + 1);
+    // Comme `free`, le pointeur étranger n'est pas touché.
+    assert_eq!(outside, [0; 32]);
+}
@@ -0,0 +1,57 @@
+//! Allocations concurrentes dans une même catégorie avec la feature
+//! `lock-free`.
+#![cfg(feature = "lock-free")]
+
+mod common;
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::thread;
+use common::layout;
+use global_allocator::SlabMemory;
+
+const THREADS: usize = 8;
+const ROUNDS: usize = 2_000;
+/// Blocs tenus à la fois par chaque thread.
+const HELD: usize = 4;
+
+#[test]
+fn concurrent_allocations_never_share_a_block() {
+    let _guard = common::setup();
+    let layout = layout(24);
+    let probe = unsafe { SlabMemory::allocate(layout) };
+    let block_size = SlabMemory::arena_of(probe).unwrap();
+    unsafe { SlabMemory::deallocate(probe, layout) };
+    let before = SlabMemory::available(block_size);
+    assert!(before >= THREADS * HELD);
+
+    let live = Mutex::new(HashSet::new());
+    thread::scope(|scope| {
+        for thread in 0..THREADS {
+            let live = &live;
+            scope.spawn(move || {
+                for round in 0..ROUNDS {
+                    let stamp = (thread * ROUNDS + round) as u8;
+                    let held: Vec<_> = (0..HELD)
+                        .map(|_| unsafe { SlabMemory::allocate(layout) })
+                        .filter(|ptr| !ptr.is_null())
+                        .collect();
+                    for &ptr in &held {
+                        assert!(live.lock().unwrap().insert(ptr.addr()), "block {:p} handed out twice", ptr);
+                        unsafe { ptr.write_bytes(stamp, layout.size()) };
+                    }
+                    thread::yield_now();
+                    for &ptr in &held {
+                        // Un autre thread qui aurait reçu le même bloc l'aurait écrasé.
+                        assert!((0..layout.size()).all(|i| unsafe { ptr.add(i).read() } == stamp));
+                        assert!(live.lock().unwrap().remove(&ptr.addr()));
+                        unsafe { SlabMemory::deallocate(ptr, layout) };
+                    }
+                }
+            });
+        }
+    });
+
+    assert!(live.lock().unwrap().is_empty());
+    assert_eq!(SlabMemory::available(block_size), before);
+}
@@ -0,0 +1,93 @@
+//! Magasins de blocs par contexte (feature `magazines`).
+#![cfg(feature = "magazines")]
+
+mod common;
+
+use std::sync::MutexGuard;
+use common::layout;
+use global_allocator::{BlockSize, SlabMemory, MAGAZINE_SIZE};
+
+/// Prépare le tas partagé, vide les magasins de `context` et retourne la
+/// catégorie des blocs de 24 octets.
+fn setup(context: usize) -> (MutexGuard<'static, ()>, BlockSize) {
+    let guard = common::setup();
+    unsafe { SlabMemory::flush_magazines(context) };
+    let probe = unsafe { SlabMemory::allocate(layout(24)) };
+    let block_size = SlabMemory::arena_of(probe).unwrap();
+    unsafe { SlabMemory::deallocate(probe, layout(24)) };
+    (guard, block_size)
+}
+
+#[test]
+fn empty_magazine_refills_from_the_arena() {
+    let (_guard, block_size) = setup(0);
+    let start = SlabMemory::available(block_size);
+
+    let first = unsafe { SlabMemory::allocate_in(0, layout(24)) };
+    assert!(!first.is_null());
+    // Un lot entier quitte l'arène ; les allocations suivantes y puisent.
+    assert_eq!(SlabMemory::available(block_size), start - MAGAZINE_SIZE / 2);
+    let rest: Vec<_> = (1..MAGAZINE_SIZE / 2)
+        .map(|_| unsafe { SlabMemory::allocate_in(0, layout(24)) })
+        .collect();
+    assert!(rest.iter().all(|ptr| !ptr.is_null()));
+    assert_eq!(SlabMemory::available(block_size), start - MAGAZINE_SIZE / 2);
+
+    // Le magasin vide est rechargé au lot suivant.
+    let next = unsafe { SlabMemory::allocate_in(0, layout(24)) };
+    assert_eq!(SlabMemory::available(block_size), start - MAGAZINE_SIZE);
+
+    for ptr in rest.into_iter().chain([first, next]) {
+        unsafe { SlabMemory::deallocate_in(0, ptr, layout(24)) };
+    }
+    unsafe { SlabMemory::flush_magazines(0) };
+    assert_eq!(SlabMemory::available(block_size), start);
+}
+
+#[test]
+fn flush_returns_every_block_when_the_context_ends() {
+    let (_guard, block_size) = setup(1);
+    let start = SlabMemory::available(block_size);
+
+    let ptr = unsafe { SlabMemory::allocate_in(1, layout(24)) };
+    unsafe { SlabMemory::deallocate_in(1, ptr, layout(24)) };
+    // Le bloc libéré reste dans le magasin avec le reste du lot.
+    assert_eq!(SlabMemory::available(block_size), start - MAGAZINE_SIZE / 2);
+
+    unsafe { SlabMemory::flush_magazines(1) };
+    assert_eq!(SlabMemory::available(block_size), start);
+    // Le magasin vidé se recharge normalement.
+    let ptr = unsafe { SlabMemory::allocate_in(1, layout(24)) };
+    assert_eq!(SlabMemory::available(block_size), start - MAGAZINE_SIZE / 2);
+    unsafe { SlabMemory::deallocate_in(1, ptr, layout(24)) };
+    unsafe { SlabMemory::flush_magazines(1) };
+}
+
+#[test]
+fn full_magazine_returns_half_to_the_arena() {
+    let (_guard, block_size) = setup(2);
+    let start = SlabMemory::available(block_size);
+
+    // Des blocs venus directement de l'arène remplissent le magasin.
+    let blocks: Vec<_> = (0..=MAGAZINE_SIZE)
+        .map(|_| unsafe { SlabMemory::allocate(layout(24)) })
+        .collect();
+    assert!(blocks.iter().all(|ptr| !ptr.is_null()));
+    for &ptr in &blocks[..MAGAZINE_SIZE] {
+        unsafe { SlabMemory::deallocate_in(2, ptr, layout(24)) };
+    }
+    assert_eq!(SlabMemory::available(block_size), start - MAGAZINE_SIZE - 1);
+
+    // Magasin plein : la moitié repart dans l'arène avant d'accepter le bloc.
+    unsafe { SlabMemory::deallocate_in(2, blocks[MAGAZINE_SIZE], layout(24)) };
+    assert_eq!(
+        SlabMemory::available(block_size),
+        start - MAGAZINE_SIZE - 1 + MAGAZINE_SIZE / 2
+    );
+    // Les blocs en réserve ne sont pas comptés comme alloués.
+    #[cfg(feature = "stats")]
+    assert_eq!(SlabMemory::stats().classes[block_size.index()].in_use, 0);
+
+    unsafe { SlabMemory::flush_magazines(2) };
+    assert_eq!(SlabMemory::available(block_size), start);
+}
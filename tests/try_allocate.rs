@@ -0,0 +1,37 @@
+//! `SlabMemory::try_allocate` donne la raison de chaque refus.
+
+mod common;
+
+use core::alloc::Layout;
+use common::{layout, setup};
+use global_allocator::{AllocationError, BlockSize, SlabMemory, ZeroSizePolicy};
+
+#[test]
+fn refusals_carry_their_reason() {
+    let _guard = setup();
+    SlabMemory::set_zero_size_policy(ZeroSizePolicy::Null);
+    assert_eq!(unsafe { SlabMemory::try_allocate(layout(0)) }, Err(AllocationError::ZeroSize));
+    SlabMemory::set_zero_size_policy(ZeroSizePolicy::Dangling);
+
+    assert_eq!(unsafe { SlabMemory::try_allocate(layout(4096)) }, Err(AllocationError::TooLarge));
+    let over_aligned = Layout::from_size_align(40, 128).unwrap();
+    assert_eq!(unsafe { SlabMemory::try_allocate(over_aligned) }, Err(AllocationError::Misaligned));
+}
+
+#[test]
+fn exhausted_class_is_out_of_memory() {
+    let _guard = setup();
+    let request = layout(500);
+    let blocks: Vec<_> = (0..SlabMemory::available(BlockSize::Colossal))
+        .map(|_| unsafe { SlabMemory::try_allocate(request) }.unwrap())
+        .collect();
+    let error = unsafe { SlabMemory::try_allocate(request) }.unwrap_err();
+    assert_eq!(error, AllocationError::OutOfMemory);
+    assert_eq!(AllocationError::try_from(i16::from(error)), Ok(error));
+
+    for ptr in blocks {
+        unsafe { SlabMemory::deallocate_non_null(ptr, request) };
+    }
+    let ptr = unsafe { SlabMemory::try_allocate(request) }.unwrap();
+    unsafe { SlabMemory::deallocate_non_null(ptr, request) };
+}
@@ -0,0 +1,121 @@
+//! Demandes de taille nulle, pour chaque `ZeroSizePolicy`.
+
+mod common;
+
+use core::alloc::Layout;
+use std::sync::MutexGuard;
+use common::layout;
+use global_allocator::{SlabMemory, ZeroSizePolicy};
+
+/// Prépare le tas partagé et installe `policy`.
+fn setup(policy: ZeroSizePolicy) -> MutexGuard<'static, ()> {
+    let guard = common::setup();
+    SlabMemory::set_zero_size_policy(policy);
+    guard
+}
+
+#[cfg(feature = "stats")]
+fn foreign_frees() -> usize {
+    SlabMemory::stats().foreign_frees
+}
+
+#[test]
+fn dangling_consumes_no_block() {
+    let _guard = setup(ZeroSizePolicy::Dangling);
+    let wide = Layout::from_size_align(0, 64).unwrap();
+    unsafe {
+        let ptr = SlabMemory::allocate(wide);
+        assert_eq!(ptr.addr(), 64);
+        assert_eq!(SlabMemory::arena_of(ptr), None);
+        assert_eq!(SlabMemory::allocate(wide), ptr);
+    }
+}
+
+#[test]
+fn dangling_free_is_ignored() {
+    let _guard = setup(ZeroSizePolicy::Dangling);
+    #[cfg(feature = "stats")]
+    let before = foreign_frees();
+    unsafe {
+        let ptr = SlabMemory::allocate(layout(0));
+        SlabMemory::deallocate(ptr, layout(0));
+        // La politique active au moment de la libération n'y change rien.
+        SlabMemory::set_zero_size_policy(ZeroSizePolicy::Block);
+        SlabMemory::deallocate(ptr, layout(0));
+    }
+    #[cfg(feature = "stats")]
+    assert_eq!(foreign_frees(), before);
+}
+
+#[test]
+fn block_gives_distinct_blocks() {
+    let _guard = setup(ZeroSizePolicy::Block);
+    unsafe {
+        let first = SlabMemory::allocate(layout(0));
+        let second = SlabMemory::allocate(layout(0));
+        assert!(!first.is_null() && !second.is_null());
+        assert_ne!(first, second);
+        assert!(SlabMemory::arena_of(first).is_some());
+        assert!(SlabMemory::arena_of(second).is_some());
+        SlabMemory::deallocate(first, layout(0));
+        SlabMemory::deallocate(second, layout(0));
+    }
+}
+
+#[test]
+fn null_fails() {
+    let _guard = setup(ZeroSizePolicy::Null);
+    unsafe {
+        assert!(SlabMemory::allocate(layout(0)).is_null());
+        assert_eq!(SlabMemory::allocate_non_null(layout(0)), None);
+    }
+}
+
+#[test]
+fn realloc_from_zero() {
+    for policy in [ZeroSizePolicy::Dangling, ZeroSizePolicy::Block] {
+        let _guard = setup(policy);
+        unsafe {
+            let ptr = SlabMemory::allocate(layout(0));
+            let grown = SlabMemory::reallocate(ptr, layout(0), 24);
+            assert!(!grown.is_null(), "{:?}", policy);
+            assert!(SlabMemory::arena_of(grown).is_some());
+            grown.write_bytes(0xA5, 24);
+            SlabMemory::deallocate(grown, layout(24));
+        }
+    }
+}
+
+#[test]
+fn realloc_to_zero() {
+    let _guard = setup(ZeroSizePolicy::Dangling);
+    unsafe {
+        let ptr = SlabMemory::allocate(layout(24));
+        let shrunk = SlabMemory::reallocate(ptr, layout(24), 0);
+        assert_eq!(shrunk.addr(), 8);
+        assert_eq!(SlabMemory::arena_of(shrunk), None);
+        SlabMemory::deallocate(shrunk, layout(0));
+        // L'ancien bloc a été libéré : il est réutilisé.
+        assert_eq!(SlabMemory::allocate(layout(24)), ptr);
+    }
+
+    SlabMemory::set_zero_size_policy(ZeroSizePolicy::Block);
+    unsafe {
+        let ptr = SlabMemory::allocate(layout(24));
+        let shrunk = SlabMemory::reallocate(ptr, layout(24), 0);
+        assert!(!shrunk.is_null());
+        assert!(SlabMemory::arena_of(shrunk).is_some());
+        SlabMemory::deallocate(shrunk, layout(0));
+    }
+
+    SlabMemory::set_zero_size_policy(ZeroSizePolicy::Null);
+    unsafe {
+        let ptr = SlabMemory::allocate(layout(24));
+        ptr.write_bytes(0x5A, 24);
+        // Sans bloc de taille nulle, le redimensionnement échoue et l'ancien
+        // bloc reste valide.
+        assert!(SlabMemory::reallocate(ptr, layout(24), 0).is_null());
+        assert!((0..24).all(|i| ptr.add(i).read() == 0x5A));
+        SlabMemory::deallocate(ptr, layout(24));
+    }
+}